@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+use aoc2023::days::day3::Day3;
+use aoc2023::solution::run_standalone;
+
+fn main() -> ExitCode {
+    run_standalone::<Day3>()
+}
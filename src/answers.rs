@@ -0,0 +1,109 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use crate::solution::Part;
+
+/// Accepted answers, one per day, part and input file.
+///
+/// The file has one answer per line as `<day> <part> <input> <answer>`, where `input` is relative
+/// to the inputs directory. Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: Vec<Expected>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = vec![];
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [day, part, input, answer] = fields[..] else {
+                return Err(format!("line {}: expected `<day> <part> <input> <answer>`, got {line:?}", idx + 1));
+            };
+            entries.push(Expected {
+                day: day.parse().map_err(|_| format!("line {}: invalid day {day:?}", idx + 1))?,
+                part: part.parse().map_err(|err| format!("line {}: {err}", idx + 1))?,
+                input: PathBuf::from(input),
+                answer: answer.to_string(),
+            });
+        }
+        Ok(Self { entries })
+    }
+
+    /// Loads an answers file. A file that doesn't exist yet has no answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn entries(&self) -> &[Expected] {
+        &self.entries
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &Path) -> Option<&str> {
+        self.entries.iter()
+            .find(|expected| expected.day == day && expected.part == part && expected.input == input)
+            .map(|expected| expected.answer.as_str())
+    }
+
+    pub fn check(&self, day: u8, part: Part, input: &Path, actual: &str) -> Outcome {
+        match self.get(day, part, input) {
+            Some(expected) if expected == actual => Outcome::Pass,
+            Some(expected) => Outcome::Fail { expected: expected.to_string() },
+            None => Outcome::Missing,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Outcome::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_check() {
+        let answers = Answers::parse("# day part input answer\n\n12 2 day12.txt 525152\n12 1 alice/day12.txt 21\n").unwrap();
+        assert_eq!(answers.entries().len(), 2);
+        assert_eq!(answers.check(12, Part::Two, Path::new("day12.txt"), "525152"), Outcome::Pass);
+        assert_eq!(answers.check(12, Part::Two, Path::new("day12.txt"), "1"), Outcome::Fail { expected: "525152".to_string() });
+        assert_eq!(answers.check(12, Part::One, Path::new("day12.txt"), "21"), Outcome::Missing);
+        assert_eq!(answers.check(12, Part::One, Path::new("alice/day12.txt"), "21"), Outcome::Pass);
+    }
+
+    #[test]
+    fn malformed_line() {
+        assert_eq!(Answers::parse("12 2 day12.txt").unwrap_err(), "line 1: expected `<day> <part> <input> <answer>`, got \"12 2 day12.txt\"");
+        assert_eq!(Answers::parse("12 3 day12.txt 1").unwrap_err(), "line 1: part must be 1 or 2, got \"3\"");
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt::Write;
+use std::time::Duration;
+use serde::Serialize;
+use crate::days::Day;
+use crate::error::ParseError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+/// Wall time of one stage of a day over several runs.
+#[derive(Clone, Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub stage: Stage,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+}
+
+impl Record {
+    fn new(day: u8, stage: Stage, mut durations: Vec<Duration>) -> Self {
+        durations.sort();
+        let mid = durations.len() / 2;
+        let median = if durations.len().is_multiple_of(2) {
+            (durations[mid - 1] + durations[mid]) / 2
+        } else {
+            durations[mid]
+        };
+
+        Self {
+            day,
+            stage,
+            runs: durations.len(),
+            min_ns: durations[0].as_nanos() as u64,
+            median_ns: median.as_nanos() as u64,
+            max_ns: durations[durations.len() - 1].as_nanos() as u64,
+        }
+    }
+}
+
+/// Runs every stage of `day` on `input` `runs` times.
+pub fn bench(day: &Day, input: &str, runs: usize) -> Result<Vec<Record>, ParseError> {
+    assert!(runs > 0, "need at least one run");
+    let timings = (0..runs).map(|_| (day.time)(input)).collect::<Result<Vec<_>, _>>()?;
+    Ok(vec![
+        Record::new(day.number, Stage::Parse, timings.iter().map(|t| t.parse).collect()),
+        Record::new(day.number, Stage::Part1, timings.iter().map(|t| t.part1).collect()),
+        Record::new(day.number, Stage::Part2, timings.iter().map(|t| t.part2).collect()),
+    ])
+}
+
+pub fn to_table(records: &[Record]) -> String {
+    let mut table = format!("{:>3}  {:<5}  {:>12}  {:>12}  {:>12}\n", "day", "stage", "min", "median", "max");
+    for record in records {
+        let _ = writeln!(
+            table,
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+            record.day,
+            record.stage.name(),
+            format!("{:.2?}", Duration::from_nanos(record.min_ns)),
+            format!("{:.2?}", Duration::from_nanos(record.median_ns)),
+            format!("{:.2?}", Duration::from_nanos(record.max_ns)),
+        );
+    }
+    table
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = "day,stage,runs,min_ns,median_ns,max_ns\n".to_string();
+    for record in records {
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            record.day, record.stage.name(), record.runs, record.min_ns, record.median_ns, record.max_ns
+        );
+    }
+    csv
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("records serialize to JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let record = Record::new(1, Stage::Parse, ms(&[5, 1, 3]));
+        assert_eq!((record.min_ns, record.median_ns, record.max_ns), (1_000_000, 3_000_000, 5_000_000));
+
+        let record = Record::new(1, Stage::Parse, ms(&[4, 1, 2, 8]));
+        assert_eq!((record.min_ns, record.median_ns, record.max_ns), (1_000_000, 3_000_000, 8_000_000));
+    }
+
+    #[test]
+    fn csv() {
+        let records = vec![Record::new(12, Stage::Part2, ms(&[2]))];
+        assert_eq!(to_csv(&records), "day,stage,runs,min_ns,median_ns,max_ns\n12,part2,1,2000000,2000000,2000000\n");
+    }
+}
@@ -0,0 +1,106 @@
+/// Where a sequence of states starts repeating: state `start + len` is the same as state `start`,
+/// with both as small as possible.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+}
+
+/// Brent's algorithm, keeping only two states at a time. Gives up once more than `limit` steps
+/// have been taken without finding the cycle length.
+fn brent<S: Clone + Eq>(initial: &S, step: &mut impl FnMut(&S) -> S, limit: Option<usize>) -> Option<Cycle> {
+    let mut power = 1;
+    let mut len = 1;
+    let mut taken = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if limit.is_some_and(|limit| taken > limit) {
+            return None;
+        }
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare);
+        len += 1;
+        taken += 1;
+    }
+
+    let mut start = 0;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..len {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Some(Cycle {
+        start,
+        len,
+    })
+}
+
+/// Finds the cycle that repeatedly applying `step` to `initial` eventually falls into. Never
+/// returns if the states don't repeat.
+pub fn find_cycle<S: Clone + Eq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    brent(initial, &mut step, None).expect("no step limit was given")
+}
+
+/// The state after applying `step` to `initial` `n` times, skipping over whole cycles.
+pub fn state_after<S: Clone + Eq>(initial: S, n: usize, mut step: impl FnMut(&S) -> S) -> S {
+    let remaining = match brent(&initial, &mut step, Some(n)) {
+        Some(cycle) => {
+            log::debug!("states repeat every {} steps from step {}", cycle.len, cycle.start);
+            cycle.reduce(n)
+        }
+        None => n,
+    };
+    (0..remaining).fold(initial, |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_cycle() {
+        // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+        let step = |n: &u32| if *n == 5 { 3 } else { n + 1 };
+        let cycle = find_cycle(&0, step);
+        assert_eq!(cycle, Cycle { start: 3, len: 3 });
+        assert_eq!(cycle.reduce(2), 2);
+        assert_eq!(cycle.reduce(1_000_000_000), 4);
+        assert_eq!(state_after(0, 1_000_000_000, step), 4);
+        assert_eq!(find_cycle(&7, |n| *n), Cycle { start: 0, len: 1 });
+    }
+
+    #[test]
+    fn state_after_matches_simulation() {
+        let step = |n: &u64| (n * n + 1) % 1009;
+        for n in 0..200 {
+            let expected = (0..n).fold(2, |state, _| step(&state));
+            assert_eq!(state_after(2, n, step), expected);
+        }
+    }
+
+    #[test]
+    fn short_runs_never_need_a_cycle() {
+        assert_eq!(state_after(0u64, 10, |n| n + 1), 10);
+    }
+}
@@ -0,0 +1,540 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead};
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+pub struct Day1 {
+    /// Part 1's sum, or why a line has no `0`-`9`.
+    digits: Result<u32, ParseError>,
+    words: u32,
+}
+
+/// Which tokens count as digits when reading a calibration line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Only `0`-`9`, as in part 1.
+    Digits,
+    /// `0`-`9` and the English words `one` to `nine`, as in part 2.
+    Words,
+}
+
+impl Mode {
+    pub const ALL: [Mode; 2] = [Mode::Digits, Mode::Words];
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Digits => write!(f, "digits"),
+            Mode::Words => write!(f, "words"),
+        }
+    }
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "digits" => Ok(Mode::Digits),
+            "words" => Ok(Mode::Words),
+            _ => Err(format!("mode must be digits or words, got {s:?}")),
+        }
+    }
+}
+
+/// A language whose number words can be read as digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [Language::English, Language::German, Language::French, Language::Spanish];
+
+    /// The words for zero to nine, indexed by value.
+    pub fn numbers(self) -> [&'static str; 10] {
+        match self {
+            Language::English => ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"],
+            Language::German => ["null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"],
+            Language::French => ["zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"],
+            Language::Spanish => ["cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve"],
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Language::English => write!(f, "english"),
+            Language::German => write!(f, "german"),
+            Language::French => write!(f, "french"),
+            Language::Spanish => write!(f, "spanish"),
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::ALL
+            .into_iter()
+            .find(|language| language.to_string() == s)
+            .ok_or_else(|| format!("language must be one of english, german, french or spanish, got {s:?}"))
+    }
+}
+
+/// The tokens read as digits, each with the value `0`-`9` it stands for.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// Just `0` to `9`.
+    pub fn digits() -> Self {
+        (0..10).fold(Self::default(), |vocabulary, value| vocabulary.insert(value.to_string(), value))
+    }
+
+    /// Adds `token` standing for `value`, replacing any earlier value for the same token. Fails
+    /// if the token is empty or the value isn't a single digit.
+    pub fn with(self, token: impl Into<String>, value: u32) -> Result<Self, String> {
+        let token = token.into();
+        if token.is_empty() {
+            return Err("digit tokens can't be empty".to_string());
+        }
+        if value >= 10 {
+            return Err(format!("{token:?} must stand for a single digit, not {value}"));
+        }
+        Ok(self.insert(token, value))
+    }
+
+    /// Adds the words for one to nine in `language`. Zero isn't a puzzle digit, so add it with
+    /// [`Vocabulary::with`] if wanted.
+    pub fn with_language(self, language: Language) -> Self {
+        language.numbers().into_iter().zip(0..).skip(1).fold(self, |vocabulary, (word, value)| vocabulary.insert(word, value))
+    }
+
+    /// Adds every token of `other`.
+    pub fn with_vocabulary(self, other: &Vocabulary) -> Self {
+        other.tokens().fold(self, |vocabulary, (token, value)| vocabulary.insert(token, value))
+    }
+
+    /// [`Vocabulary::with`] for tokens already known to be valid.
+    fn insert(mut self, token: impl Into<String>, value: u32) -> Self {
+        let token = token.into();
+        self.tokens.retain(|(existing, _)| *existing != token);
+        self.tokens.push((token, value));
+        self
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.tokens.iter().map(|(token, value)| (token.as_str(), *value))
+    }
+}
+
+impl From<Mode> for Vocabulary {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Digits => Vocabulary::digits(),
+            Mode::Words => Vocabulary::digits().with_language(Language::English),
+        }
+    }
+}
+
+/// Finds the first and last digit token of each line in a single pass, using an Aho-Corasick
+/// automaton so overlapping tokens like `eightwo` are all seen.
+pub struct Scanner {
+    vocabulary: Vocabulary,
+    /// Next state for every state and input byte, with failure links already followed.
+    transitions: Vec<[u32; 256]>,
+    /// Index into the vocabulary of every token ending in each state, including shorter suffixes.
+    outputs: Vec<Vec<usize>>,
+}
+
+/// A token found on a line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match<'a> {
+    /// Byte offset of the token's start.
+    pub offset: usize,
+    pub token: &'a str,
+    pub value: u32,
+}
+
+/// Every token found on a line, in order, and the calibration value they give.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnosis<'a> {
+    pub matches: Vec<Match<'a>>,
+    /// `None` if the line has no digit.
+    pub value: Option<u32>,
+}
+
+impl Display for Diagnosis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for m in &self.matches {
+            write!(f, "{}@{} ", m.token, m.offset)?;
+        }
+        match self.value {
+            Some(value) => write!(f, "=> {value}"),
+            None => write!(f, "no digit"),
+        }
+    }
+}
+
+/// The first and last token found so far on a line, as `(start, value)`.
+#[derive(Default)]
+struct LineState {
+    state: u32,
+    pos: usize,
+    first: Option<(usize, u32)>,
+    last: Option<(usize, u32)>,
+}
+
+impl LineState {
+    /// Advances over `byte`, calling `found` with the start and vocabulary index of every token
+    /// ending there.
+    fn feed(&mut self, scanner: &Scanner, byte: u8, mut found: impl FnMut(usize, usize)) {
+        self.state = scanner.transitions[self.state as usize][byte as usize];
+        self.pos += 1;
+        for &token in &scanner.outputs[self.state as usize] {
+            let (text, value) = &scanner.vocabulary.tokens[token];
+            let start = self.pos - text.len();
+            // Longer tokens end later, so at the same start they win: `IV` reads as 4, not 1.
+            if self.first.is_none_or(|(first, _)| start <= first) {
+                self.first = Some((start, *value));
+            }
+            if self.last.is_none_or(|(last, _)| start >= last) {
+                self.last = Some((start, *value));
+            }
+            found(start, token);
+        }
+    }
+
+    fn finish(&mut self) -> Option<u32> {
+        let LineState { first, last, .. } = std::mem::take(self);
+        Some(first?.1 * 10 + last?.1)
+    }
+}
+
+impl Scanner {
+    /// Builds a scanner recognising the tokens of `vocabulary`.
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        const MISSING: u32 = u32::MAX;
+        let mut transitions = vec![[MISSING; 256]];
+        let mut outputs = vec![vec![]];
+        for (idx, (token, _)) in vocabulary.tokens().enumerate() {
+            let mut state = 0;
+            for &byte in token.as_bytes() {
+                if transitions[state][byte as usize] == MISSING {
+                    transitions[state][byte as usize] = transitions.len() as u32;
+                    transitions.push([MISSING; 256]);
+                    outputs.push(vec![]);
+                }
+                state = transitions[state][byte as usize] as usize;
+            }
+            outputs[state].push(idx);
+        }
+
+        // Breadth-first, so each state's failure target is complete before its children need it.
+        let mut fail = vec![0; transitions.len()];
+        let mut queue = VecDeque::from([0]);
+        while let Some(state) = queue.pop_front() {
+            let fallbacks = if state == 0 { [0; 256] } else { transitions[fail[state]] };
+            for (byte, fallback) in fallbacks.into_iter().enumerate() {
+                let next = transitions[state][byte];
+                if next == MISSING {
+                    transitions[state][byte] = fallback;
+                } else {
+                    let next = next as usize;
+                    fail[next] = fallback as usize;
+                    let inherited = outputs[fail[next]].clone();
+                    outputs[next].extend(inherited);
+                    queue.push_back(next);
+                }
+            }
+        }
+        Self {
+            vocabulary: vocabulary.clone(),
+            transitions,
+            outputs,
+        }
+    }
+
+    /// A scanner for the digit tokens of `mode`.
+    pub fn for_mode(mode: Mode) -> Self {
+        Self::new(&mode.into())
+    }
+
+    /// The calibration value of `line`, or `None` if it has no digit.
+    pub fn scan(&self, line: &str) -> Option<u32> {
+        let mut state = LineState::default();
+        for &byte in line.as_bytes() {
+            state.feed(self, byte, |_, _| ());
+        }
+        state.finish()
+    }
+
+    /// Every token on `line` and the calibration value they give.
+    pub fn diagnose(&self, line: &str) -> Diagnosis<'_> {
+        let mut state = LineState::default();
+        let mut matches = vec![];
+        for &byte in line.as_bytes() {
+            state.feed(self, byte, |offset, token| {
+                let (token, value) = &self.vocabulary.tokens[token];
+                matches.push(Match { offset, token, value: *value });
+            });
+        }
+        matches.sort_by_key(|m| (m.offset, m.token.len()));
+        Diagnosis { matches, value: state.finish() }
+    }
+
+    /// Diagnoses every line read from `reader`, handing each to `report` with its 1-based line
+    /// number, and sums their values. Unlike [`Scanner::calibrate`] it reads on past lines with
+    /// no digit, then fails naming all of them.
+    pub fn explain(&self, reader: impl BufRead, mut report: impl FnMut(usize, &str, &Diagnosis<'_>)) -> io::Result<u64> {
+        let mut sum = 0;
+        let mut missing = vec![];
+        for (idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            let diagnosis = self.diagnose(&line);
+            report(idx + 1, &line, &diagnosis);
+            match diagnosis.value {
+                Some(value) => sum += u64::from(value),
+                None => missing.push(idx + 1),
+            }
+        }
+        match missing.as_slice() {
+            [] => Ok(sum),
+            [line] => Err(io::Error::new(io::ErrorKind::InvalidData, format!("no digit on line {line}"))),
+            lines => Err(io::Error::new(io::ErrorKind::InvalidData, format!("no digit on lines {}", lines.iter().join(", ")))),
+        }
+    }
+
+    /// Sums the calibration value of every line read from `reader`, without holding more than
+    /// its buffer in memory.
+    pub fn calibrate(&self, mut reader: impl BufRead) -> io::Result<u64> {
+        let mut sum = 0;
+        let mut line = LineState::default();
+        let mut line_number = 1;
+        let mut finish_line = |line: &mut LineState, line_number: &mut usize| {
+            let value = line.finish().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, ParseError::new(*line_number, 1, "", "no digit"))
+            })?;
+            sum += u64::from(value);
+            *line_number += 1;
+            io::Result::Ok(())
+        };
+        // A `\r` is only part of the line ending right before a `\n` or the end of the input, so
+        // hold each one back until the next byte shows which.
+        let mut pending_cr = false;
+        loop {
+            let buf = reader.fill_buf()?;
+            if buf.is_empty() {
+                break;
+            }
+            for &byte in buf {
+                if byte == b'\n' {
+                    pending_cr = false;
+                    finish_line(&mut line, &mut line_number)?;
+                    continue;
+                }
+                if pending_cr {
+                    line.feed(self, b'\r', |_, _| ());
+                }
+                pending_cr = byte == b'\r';
+                if !pending_cr {
+                    line.feed(self, byte, |_, _| ());
+                }
+            }
+            let len = buf.len();
+            reader.consume(len);
+        }
+        if line.pos > 0 || pending_cr {
+            finish_line(&mut line, &mut line_number)?;
+        }
+        Ok(sum)
+    }
+}
+
+/// The calibration value of every line: its first and last digit, reading digits as `mode` says.
+pub fn calc(input: &str, mode: Mode) -> Result<Vec<u32>, ParseError> {
+    let scanner = Scanner::for_mode(mode);
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let value = scanner.scan(line).ok_or_else(|| ParseError::new(idx + 1, 1, line, "no digit"))?;
+            log::trace!("{line} --> {value}");
+            Ok(value)
+        })
+        .collect()
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Answer = Result<u32, ParseError>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        // Every line needs at least a spelled-out digit. Lines without a `0`-`9` only fail part 1.
+        let words = calc(input, Mode::Words)?.into_iter().sum();
+        Ok(Self {
+            digits: calc(input, Mode::Digits).map(|values| values.into_iter().sum()),
+            words,
+        })
+    }
+
+    fn part1(&self) -> Result<u32, ParseError> {
+        self.digits.clone()
+    }
+
+    fn part2(&self) -> Result<u32, ParseError> {
+        Ok(self.words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+    use proptest::prelude::*;
+    use crate::solution::{solve, Part};
+    use super::*;
+
+    /// Tries every token at every offset, as the scanner should behave.
+    fn naive(line: &str, mode: Mode) -> Option<u32> {
+        let words = &Language::English.numbers()[1..];
+        let values = (0..line.len())
+            .filter_map(|idx| {
+                let rest = &line[idx..];
+                let digit = rest.chars().next()?.to_digit(10);
+                let word = words.iter().position(|word| rest.starts_with(word)).map(|idx| idx as u32 + 1);
+                digit.or(word.filter(|_| mode == Mode::Words))
+            })
+            .collect::<Vec<_>>();
+        Some(values.first()? * 10 + values.last()?)
+    }
+
+    #[test]
+    fn example_day1_1() {
+        let input = r#"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet"#;
+        let results = calc(input, Mode::Digits).unwrap();
+        assert_eq!(results.into_iter().sum::<u32>(), 142);
+        // Without words in the input both modes agree.
+        assert_eq!(calc(input, Mode::Words), calc(input, Mode::Digits));
+    }
+
+    #[test]
+    fn example_day1_2() {
+        let input = r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"#;
+        let results = calc(input, Mode::Words).unwrap();
+        assert_eq!(results.into_iter().sum::<u32>(), 281);
+        // Reading digits only ignores every spelled-out word.
+        let with_digits = input.lines().filter(|line| line.contains(|ch: char| ch.is_ascii_digit())).collect::<Vec<_>>().join("\n");
+        assert_eq!(calc(&with_digits, Mode::Digits).unwrap(), [11, 22, 33, 42, 24, 77]);
+        assert_eq!(calc(input, Mode::Digits).unwrap_err(), ParseError::new(2, 1, "eightwothree", "no digit"));
+
+        // The whole day still runs: part 1 reports the line it can't read instead of panicking.
+        assert_eq!(solve::<Day1>(input, Part::Two).unwrap(), "281");
+        let err = solve::<Day1>(input, Part::One).unwrap_err();
+        assert_eq!(err.to_string(), "day 1: line 2, column 1: no digit at \"eightwothree\"");
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let scanner = Scanner::for_mode(Mode::Words);
+        assert_eq!(scanner.scan("eightwo"), Some(82));
+        assert_eq!(scanner.scan("xoneight"), Some(18));
+        assert_eq!(scanner.scan("sevenine"), Some(79));
+        assert_eq!(scanner.scan("twone"), Some(21));
+        assert_eq!(scanner.scan("thre"), None);
+        assert_eq!(Scanner::for_mode(Mode::Digits).scan("eightwo"), None);
+    }
+
+    #[test]
+    fn calibrate_streams_across_buffer_boundaries() {
+        let input = "two1nine\r\neightwothree\r\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n";
+        let scanner = Scanner::for_mode(Mode::Words);
+        for capacity in [1, 2, 3, 7, 64] {
+            assert_eq!(scanner.calibrate(BufReader::with_capacity(capacity, input.as_bytes())).unwrap(), 281);
+        }
+
+        // Only a `\r` ending a line is dropped. One inside a line splits `on\re`, as `str::lines` does.
+        for (input, line) in [("1\r\non\re\r\n2", 2), ("1\n2\r\non\re\r", 3)] {
+            for capacity in [1, 2, 64] {
+                let err = scanner.calibrate(BufReader::with_capacity(capacity, input.as_bytes())).unwrap_err();
+                assert_eq!(err.to_string(), format!("line {line}, column 1: no digit"));
+                assert!(calc(input, Mode::Words).is_err());
+            }
+        }
+        assert_eq!(scanner.calibrate("one\r".as_bytes()).unwrap(), 11);
+        assert_eq!(scanner.calibrate("1\n\r".as_bytes()).unwrap_err().to_string(), "line 2, column 1: no digit");
+
+        let err = scanner.calibrate("1\n\nfour".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: no digit");
+    }
+
+    #[test]
+    fn explain_names_lines_without_digits() {
+        let scanner = Scanner::for_mode(Mode::Digits);
+        let mut reported = vec![];
+        let err = scanner.explain("two1nine\neightwothree\n7\nnone".as_bytes(), |line, text, diagnosis| {
+            reported.push(format!("{line} {text}: {diagnosis}"));
+        }).unwrap_err();
+        assert_eq!(err.to_string(), "no digit on lines 2, 4");
+        assert_eq!(reported, ["1 two1nine: 1@3 => 11", "2 eightwothree: no digit", "3 7: 7@0 => 77", "4 none: no digit"]);
+
+        assert_eq!(scanner.explain("a1\n2b".as_bytes(), |_, _, _| ()).unwrap(), 33);
+    }
+
+    #[test]
+    fn custom_vocabularies() {
+        let german = Scanner::new(&Vocabulary::digits().with_language(Language::German).with("null", 0).unwrap());
+        assert_eq!(german.scan("nulleinsxdreizehn"), Some(3));
+        assert_eq!(german.scan("achtzweins"), Some(81));
+        assert_eq!(Scanner::new(&Vocabulary::default().with_language(Language::French)).scan("1deuxhuitre"), Some(28));
+
+        let custom = Scanner::new(&Vocabulary::default().with("I", 1).and_then(|vocabulary| vocabulary.with("IV", 4)).unwrap());
+        assert_eq!(custom.scan("xIVx"), Some(44));
+        assert_eq!(custom.scan("IIV"), Some(14));
+        assert_eq!(custom.scan("12"), None);
+
+        assert_eq!(Vocabulary::default().with("", 1).unwrap_err(), "digit tokens can't be empty");
+        assert_eq!(Vocabulary::default().with("ten", 10).unwrap_err(), "\"ten\" must stand for a single digit, not 10");
+    }
+
+    #[test]
+    fn diagnose_lists_every_token() {
+        let scanner = Scanner::new(&Vocabulary::from(Mode::Words).with("zero", 0).unwrap());
+        let diagnosis = scanner.diagnose("zeroneightwo");
+        let tokens = diagnosis.matches.iter().map(|m| (m.offset, m.token, m.value)).collect::<Vec<_>>();
+        assert_eq!(tokens, [(0, "zero", 0), (3, "one", 1), (5, "eight", 8), (9, "two", 2)]);
+        assert_eq!(diagnosis.value, Some(2));
+        assert_eq!(diagnosis.to_string(), "zero@0 one@3 eight@5 two@9 => 2");
+        assert_eq!(scanner.diagnose("abc").to_string(), "no digit");
+    }
+
+    proptest! {
+        #[test]
+        fn scan_matches_naive(line in "[0-9a-z]{0,3}(one|two|three|four|five|six|seven|eight|nine|[a-z0-9]){0,8}") {
+            for mode in Mode::ALL {
+                let scanner = Scanner::for_mode(mode);
+                prop_assert_eq!(scanner.scan(&line), naive(&line, mode));
+                prop_assert_eq!(scanner.diagnose(&line).value, naive(&line, mode));
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::point::Dir;
+use crate::search;
+use crate::solution::Solution;
+
+pub struct Day10 {
+    map: Map,
+}
+
+/// The pipe map, with the start tile replaced by the pipe it stands on.
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<char>,
+    start: Pos,
+}
+
+impl Map {
+    pub fn from(tiles: Grid<char>) -> Result<Self, ParseError> {
+        let starts: Vec<Pos> = tiles.iter().filter(|(_, tile)| **tile == 'S').map(|(pos, _)| pos).collect();
+        let start = match starts[..] {
+            [] => return Err(ParseError::new(1, 1, "", "no start tile")),
+            [start] => start,
+            [_, (x, y), ..] => return Err(ParseError::new(y + 1, x + 1, "S", "more than one start tile")),
+        };
+
+        Ok(Self {
+            tiles,
+            start,
+        })
+    }
+
+    pub fn fix_start_tile(&mut self) -> Result<(), ParseError> {
+        let connects = |dir| self.tiles.step(self.start, dir)
+            .is_some_and(|pos| self.exits(pos).contains(&self.start));
+        let w = connects(Dir::West);
+        let e = connects(Dir::East);
+        let n = connects(Dir::North);
+        let s = connects(Dir::South);
+        let tile = match(w, e, n, s) {
+            (true, true, false, false) => '-',
+            (true, false, true, false) => 'J',
+            (true, false, false, true) => '7',
+            (false, true, true, false) => 'L',
+            (false, false, true, true) => '|',
+            (false, true, false, true) => 'F',
+            _ => {
+                let (x, y) = (self.start.0 + 1, self.start.1 + 1);
+                return Err(ParseError::new(y, x, "S", "start tile doesn't connect to exactly two pipes"));
+            }
+        };
+        self.tiles[self.start] = tile;
+        Ok(())
+    }
+
+    /// The number of steps along the loop from the start tile to every tile on it.
+    pub fn calc_distances(&self) -> HashMap<Pos, usize> {
+        search::bfs(self.start, |pos| self.exits(*pos)).into_costs()
+    }
+
+    pub fn calc_num_enclosed_tiles(&self) -> usize {
+        let distances = self.calc_distances();
+        let mut enclosed = 0;
+        for (y, line) in self.tiles.rows().enumerate() {
+            let mut is_inside = false;
+            for (x, tile) in line.iter().enumerate() {
+                let is_loop_tile = distances.contains_key(&(x, y));
+                if is_loop_tile && ['|', 'L', 'J'].contains(tile) {
+                    is_inside = !is_inside;
+                }
+                if is_inside && !is_loop_tile {
+                    enclosed += 1;
+                }
+            }
+        }
+        enclosed
+    }
+
+    pub fn calc_max_distance(&self) -> usize {
+        *self.calc_distances().values().max().unwrap()
+    }
+
+    pub fn exits(&self, pos: Pos) -> Vec<Pos> {
+        let dirs: &[Dir] = match self.tiles[pos] {
+            '|' => &[Dir::North, Dir::South],
+            '-' => &[Dir::West, Dir::East],
+            'L' => &[Dir::North, Dir::East],
+            'J' => &[Dir::North, Dir::West],
+            '7' => &[Dir::South, Dir::West],
+            'F' => &[Dir::South, Dir::East],
+            '.' | 'S' => &[],
+            ty => unreachable!("unknown tile {ty:?} should have been rejected when parsing"),
+        };
+        dirs.iter().filter_map(|dir| self.tiles.step(pos, *dir)).collect()
+    }
+}
+
+/// Parses the pipe map and works out which pipe is under the start tile.
+pub fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let tiles = Grid::parse_only(input, &['|', '-', 'L', 'J', '7', 'F', '.', 'S'])?;
+    let mut map = Map::from(tiles)?;
+    map.fix_start_tile()?;
+    Ok(map)
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.map.calc_max_distance()
+    }
+
+    fn part2(&self) -> usize {
+        self.map.calc_num_enclosed_tiles()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let input = r#".....
+.S-7.
+.|.|.
+.L-J.
+....."#;
+        let map = parse_input(input).unwrap();
+        assert_eq!(map.calc_max_distance(), 4);
+    }
+
+    #[test]
+    fn example2() {
+        let input = r#"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."#;
+        let map = parse_input(input).unwrap();
+        assert_eq!(map.calc_max_distance(), 8);
+    }
+
+    #[test]
+    fn example3() {
+        let input = r#"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."#;
+        let map = parse_input(input).unwrap();
+        assert_eq!(map.calc_num_enclosed_tiles(), 4);
+    }
+
+    #[test]
+    fn example4() {
+        let input = r#".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ..."#;
+        let map = parse_input(input).unwrap();
+        assert_eq!(map.calc_num_enclosed_tiles(), 8);
+    }
+
+    #[test]
+    fn example5() {
+        let input = r#"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"#;
+        let map = parse_input(input).unwrap();
+        assert_eq!(map.calc_num_enclosed_tiles(), 10);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::solution::Solution;
+
+pub struct Day11 {
+    image: Grid<char>,
+}
+
+/// The distance between every pair of galaxies, after inserting `expansion_factor` extra
+/// rows and columns for every empty one.
+pub fn shortest_paths(image: &Grid<char>, expansion_factor: i64) -> Vec<usize> {
+    let rows_to_expand: Vec<i64> = (0..image.height())
+        .filter(|y| image.row(*y).iter().all(|ch| *ch == '.'))
+        .map(|y| y as i64)
+        .collect();
+    let columns_to_expand: Vec<i64> = (0..image.width())
+        .filter(|x| image.column(*x).all(|ch| *ch == '.'))
+        .map(|x| x as i64)
+        .collect();
+
+    let galaxies: Vec<Point> = image.iter()
+        .filter(|(_, ch)| **ch == '#')
+        .map(|((x, y), _)| {
+            let (x, y) = (x as i64, y as i64);
+            let empty_columns = columns_to_expand.iter().filter(|e| **e < x).count() as i64;
+            let empty_rows = rows_to_expand.iter().filter(|e| **e < y).count() as i64;
+            Point::new(x + expansion_factor * empty_columns, y + expansion_factor * empty_rows)
+        })
+        .collect();
+
+    galaxies.iter()
+        .tuple_combinations()
+        .map(|(a, b)| a.manhattan(*b) as usize)
+        .collect()
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            image: Grid::parse_only(input, &['.', '#'])?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        shortest_paths(&self.image, 1).into_iter().sum()
+    }
+
+    fn part2(&self) -> usize {
+        shortest_paths(&self.image, 999999).into_iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+        let input = Grid::parse_chars(input).unwrap();
+        assert_eq!(shortest_paths(&input, 1).into_iter().sum::<usize>(), 374);
+        assert_eq!(shortest_paths(&input, 9).into_iter().sum::<usize>(), 1030);
+        assert_eq!(shortest_paths(&input, 99).into_iter().sum::<usize>(), 8410);
+    }
+}
\ No newline at end of file
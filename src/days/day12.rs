@@ -0,0 +1,158 @@
+use rayon::prelude::*;
+use crate::error::ParseError;
+use crate::memo::Memo;
+use crate::solution::Solution;
+
+pub struct Day12 {
+    rows: Vec<Row>,
+}
+
+pub struct Row {
+    pub springs: String,
+    /// The sizes of the groups of damaged springs, in order.
+    pub expected: Vec<usize>,
+}
+
+/// Parses one `<springs> <group sizes>` record per line.
+pub fn parse_rows(input: &str) -> Result<Vec<Row>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let Some((springs, groups)) = line.split_once(' ') else {
+                return Err(ParseError::new(idx + 1, 1, line, "expected `<springs> <groups>`"));
+            };
+            if let Some(x) = springs.find(|ch| !['.', '#', '?'].contains(&ch)) {
+                return Err(ParseError::new(idx + 1, x + 1, &springs[x..], "expected `.`, `#` or `?`"));
+            }
+            let expected = groups.split(',')
+                .map(|group| group.parse::<usize>().map_err(|_| {
+                    let column = group.as_ptr() as usize - line.as_ptr() as usize + 1;
+                    ParseError::new(idx + 1, column, group, "expected a group size")
+                }))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Row {
+                springs: springs.to_string(),
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// The number of arrangements matching each row, optionally unfolded five times.
+pub fn calc_combinations(rows: &[Row], unfold: bool) -> Vec<usize> {
+    rows.par_iter()
+        .map(|row| {
+            let springs = row.springs.clone();
+            let mut expected = row.expected.clone();
+
+            let springs = if unfold {
+                let s = springs.to_string();
+                s.clone() + "?" + &s.clone() + "?" + &s.clone() + "?" + &s.clone() + "?" + &s.clone()
+            } else {
+                springs
+            };
+
+            if unfold {
+                expected = vec![expected.clone(), expected.clone(), expected.clone(), expected.clone(), expected.clone()].into_iter().flatten().collect::<Vec<_>>();
+            }
+
+            calc(&mut Memo::new(), &springs, &expected, 0, 0, 0)
+        })
+        .collect()
+}
+
+pub type Cache = Memo<(usize, usize, usize), usize>;
+
+/// The number of arrangements of `springs` from `springs_pos` on that match the `expected` groups
+/// from `cur_group` on, given the length of the damaged group being matched so far.
+pub fn calc(cache: &mut Cache, springs: &str, expected: &[usize], springs_pos: usize, cur_group: usize, inside_group_length: usize) -> usize {
+    cache.get_or_insert_with((springs_pos, cur_group, inside_group_length), |cache| {
+        if springs_pos == springs.len() {
+            return if (inside_group_length == 0 && cur_group == expected.len()) || (cur_group == expected.len() - 1 && expected[cur_group] == inside_group_length) {
+                1
+            } else {
+                0
+            };
+        }
+
+        let mut result = 0;
+
+        if springs.chars().nth(springs_pos) == Some('#') || springs.chars().nth(springs_pos) == Some('?') {
+            result += calc(cache, springs, expected, springs_pos + 1, cur_group, inside_group_length + 1);
+        }
+
+        if springs.chars().nth(springs_pos) == Some('.') || springs.chars().nth(springs_pos) == Some('?') {
+            if inside_group_length == 0 {
+                result += calc(cache, springs, expected, springs_pos + 1, cur_group, 0);
+            } else if cur_group < expected.len() && expected[cur_group] == inside_group_length {
+                result += calc(cache, springs, expected, springs_pos + 1, cur_group + 1, 0);
+            }
+        }
+
+        result
+    })
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            rows: parse_rows(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        calc_combinations(&self.rows, false).into_iter().sum()
+    }
+
+    fn part2(&self) -> usize {
+        calc_combinations(&self.rows, true).into_iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_basic() {
+        let input = "?.# 1,1";
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), false).into_iter().sum::<usize>(), 1);
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), true).into_iter().sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn example_simple() {
+        let input = "???.### 1,1,3";
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), false).into_iter().sum::<usize>(), 1);
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), true).into_iter().sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn example_simple2() {
+        let input = "?###???????? 3,2,1";
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), false).into_iter().sum::<usize>(), 10);
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), true).into_iter().sum::<usize>(), 506250);
+    }
+
+    #[test]
+    fn example_simple3() {
+        let input = "????.######..#####. 1,6,5";
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), true).into_iter().sum::<usize>(), 2500);
+    }
+
+    #[test]
+    fn example1() {
+        let input = r#"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1"#;
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), false).into_iter().sum::<usize>(), 21);
+        assert_eq!(calc_combinations(&parse_rows(input).unwrap(), true).into_iter().sum::<usize>(), 525152);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,207 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct Day13 {
+    patterns: Vec<Pattern>,
+}
+
+/// One pattern of ash and rocks.
+pub struct Pattern {
+    /// 1-based line the pattern starts on, for errors.
+    pub first_line: usize,
+    pub tiles: Grid<char>,
+}
+
+/// Parses the blank-line separated patterns.
+pub fn parse_patterns(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = vec![];
+    let mut first_line = 1;
+    let mut pat = String::new();
+    for (y, line) in input.lines().chain([""]).enumerate() {
+        if !line.is_empty() {
+            pat.push_str(line);
+            pat.push('\n');
+        } else if !pat.is_empty() {
+            let tiles = Grid::parse_only(&pat, &['.', '#']).map_err(|e| e.on_line(first_line))?;
+            patterns.push(Pattern { first_line, tiles });
+            pat.clear();
+        }
+        if line.is_empty() {
+            first_line = y + 2;
+        }
+    }
+    Ok(patterns)
+}
+
+/// The summary of every pattern's line of reflection, optionally after fixing its smudge. Fails
+/// on the first pattern without one.
+pub fn find_reflections(patterns: &[Pattern], smudged: bool) -> Result<Vec<usize>, ParseError> {
+    patterns.iter()
+        .map(|pat| {
+            let reflection = if smudged {
+                find_smudged_reflection(&pat.tiles)
+            } else {
+                find_reflection(&pat.tiles, None)
+            };
+            reflection.ok_or_else(|| {
+                let message = if smudged { "no line of reflection after fixing a smudge" } else { "no line of reflection" };
+                ParseError::new(pat.first_line, 1, pat.tiles.row(0).iter().collect::<String>(), message)
+            })
+        })
+        .collect()
+}
+
+/// The summary of the new line of reflection after flipping exactly one tile, if there is one.
+pub fn find_smudged_reflection(pat: &Grid<char>) -> Option<usize> {
+    let original_reflection = find_reflection(pat, None);
+    let mut smudged = pat.clone();
+    for pos in pat.positions() {
+        let orig = pat[pos];
+        smudged[pos] = if orig == '.' {
+            '#'
+        } else {
+            '.'
+        };
+
+        if let Some(refl) = find_reflection(&smudged, original_reflection) {
+            return Some(refl);
+        }
+        smudged[pos] = orig;
+    }
+    None
+}
+
+/// Every line of reflection between two rows, as the number of rows above it.
+fn mirrored_rows(pat: &Grid<char>) -> impl Iterator<Item = usize> + '_ {
+    (1..pat.height()).filter(|y| (0..*y).rev().zip(*y..pat.height()).all(|(a, b)| pat.row(a) == pat.row(b)))
+}
+
+/// The summary of the first line of reflection other than `ignore`: the number of columns to
+/// its left, or 100 times the number of rows above it.
+pub fn find_reflection(pat: &Grid<char>, ignore: Option<usize>) -> Option<usize> {
+    // Vertical
+    if let Some(val) = mirrored_rows(pat).map(|y| y * 100).find(|val| Some(*val) != ignore) {
+        return Some(val);
+    }
+
+    // Horizontal
+    mirrored_rows(&pat.transpose()).find(|val| Some(*val) != ignore)
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Answer = Result<usize, ParseError>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            patterns: parse_patterns(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize, ParseError> {
+        Ok(find_reflections(&self.patterns, false)?.into_iter().sum())
+    }
+
+    fn part2(&self) -> Result<usize, ParseError> {
+        Ok(find_reflections(&self.patterns, true)?.into_iter().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_x() {
+        let input = r#"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#."#;
+        assert_eq!(find_reflection(&Grid::parse_chars(input).unwrap(), None), Some(5));
+    }
+
+    #[test]
+    fn test_x_smudged() {
+        let input = r#"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#."#;
+        assert_eq!(find_smudged_reflection(&Grid::parse_chars(input).unwrap()), Some(300));
+    }
+
+    #[test]
+    fn test_y() {
+        let input = r#"#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#;
+        assert_eq!(find_reflection(&Grid::parse_chars(input).unwrap(), None), Some(400));
+    }
+
+    #[test]
+    fn test_y_smudged() {
+        let input = r#"#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#;
+        assert_eq!(find_smudged_reflection(&Grid::parse_chars(input).unwrap()), Some(100));
+    }
+
+    #[test]
+    fn borked() {
+        let input = r#"#.##..#..######..
+....##.##..##..##
+###...##..#..#..#
+###...##..#..#..#
+....##.##..##..##
+#.##..#..######..
+.#..###.###..###.
+####...###...####
+######..########.
+.#..#....#.##.#..
+##...###.#....#.#"#;
+        assert_eq!(find_smudged_reflection(&Grid::parse_chars(input).unwrap()), Some(12));
+    }
+
+    #[test]
+    fn example1() {
+        let input = r#"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#;
+        let patterns = parse_patterns(input).unwrap();
+        assert_eq!(find_reflections(&patterns, false).unwrap().into_iter().sum::<usize>(), 405);
+        assert_eq!(find_reflections(&patterns, true).unwrap().into_iter().sum::<usize>(), 400);
+    }
+
+    #[test]
+    fn no_reflection() {
+        let day = Day13::parse("##\n##\n\n#.\n.#").unwrap();
+        assert_eq!(day.part1().unwrap_err().to_string(), "line 4, column 1: no line of reflection at \"#.\"");
+        assert_eq!(day.part2().unwrap_err().to_string(), "line 1, column 1: no line of reflection after fixing a smudge at \"##\"");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use crate::cycle;
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct Day14 {
+    platform: Grid<char>,
+}
+
+/// Rolls every round rock as far north as it will go.
+pub fn tilt_north(platform: &mut Grid<char>) {
+    for x in 0..platform.width() {
+        let mut free = 0;
+        for y in 0..platform.height() {
+            match platform[(x, y)] {
+                '#' => free = y + 1,
+                'O' => {
+                    platform[(x, y)] = '.';
+                    platform[(x, free)] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Tilts north, west, south and east in turn by rotating the platform under a north tilt.
+pub fn spin_cycle(platform: &Grid<char>) -> Grid<char> {
+    let mut platform = platform.clone();
+    for _ in 0..4 {
+        tilt_north(&mut platform);
+        platform = platform.rotate_cw();
+    }
+    platform
+}
+
+/// The load on the north beams after tilting north once, or after a billion spin cycles.
+pub fn calc_load(platform: &Grid<char>, spin: bool) -> usize {
+    let platform = if spin {
+        cycle::state_after(platform.clone(), 1_000_000_000, spin_cycle)
+    } else {
+        let mut platform = platform.clone();
+        tilt_north(&mut platform);
+        platform
+    };
+
+    platform.iter()
+        .filter(|(_, ch)| **ch == 'O')
+        .map(|((_, y), _)| platform.height() - y)
+        .sum()
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            platform: Grid::parse_only(input, &['.', '#', 'O'])?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        calc_load(&self.platform, false)
+    }
+
+    fn part2(&self) -> usize {
+        calc_load(&self.platform, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let input = r#"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."#;
+        let input = Grid::parse_chars(input).unwrap();
+        assert_eq!(calc_load(&input, false), 136);
+        assert_eq!(calc_load(&input, true), 64);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+pub struct Day15 {
+    input: String,
+    ops: Vec<Op>,
+}
+
+pub enum Op {
+    Insert(String, usize),
+    Remove(String),
+}
+
+/// Parses the comma-separated initialization sequence.
+pub fn parse_ops(input: &str) -> Result<Vec<Op>, ParseError> {
+    input.split(',')
+        .map(|item| {
+            let error = |message| ParseError::at(input, &input[item.as_ptr() as usize - input.as_ptr() as usize..], message);
+            if let Some((id, value)) = item.split_once('=') {
+                let value = value.parse::<usize>().map_err(|_| error("expected a focal length"))?;
+                Ok(Op::Insert(id.to_string(), value))
+            } else if let Some(id) = item.strip_suffix('-') {
+                Ok(Op::Remove(id.to_string()))
+            } else {
+                Err(error("expected `<label>=<focal length>` or `<label>-`"))
+            }
+        })
+        .collect()
+}
+
+/// The sum of the hashes of every comma-separated step.
+pub fn hash_csv(input: &str) -> usize {
+    input.split(',').map(hash).sum::<usize>()
+}
+
+/// The total focusing power after running every operation against the boxes.
+pub fn hash_map(ops: &[Op]) -> usize {
+    let mut maps: Vec<Vec<(String, usize)>> = vec![];
+    for _ in 0..256 {
+        maps.push(vec![]);
+    }
+
+    for op in ops {
+        if let Op::Insert(id, value) = op {
+            let value = *value;
+            let box_id = hash(id);
+            let bx = &mut maps[box_id];
+
+            let mut found = false;
+            for (lens_id, lens_value) in bx.iter_mut() {
+                if lens_id == id {
+                    *lens_value = value;
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                bx.push((id.to_string(), value));
+            }
+        } else if let Op::Remove(id) = op {
+            let box_id = hash(id);
+            let bx = &mut maps[box_id];
+            for i in 0..bx.len() {
+                if bx[i].0 == *id {
+                    bx.remove(i);
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut total = 0;
+    for (i, items) in maps.iter().enumerate() {
+        if !items.is_empty() {
+            log::trace!("box {i}: {}", items.iter().map(|(id, value)| format!("{id} {value}")).join(", "));
+            for (slot, (_, value)) in items.iter().enumerate() {
+                total += (i + 1) * (slot + 1) * *value;
+            }
+        }
+    }
+
+    total
+}
+
+/// The HASH algorithm: a running `(value + byte) * 17 % 256`.
+pub fn hash(input: &str) -> usize {
+    let mut result = 0;
+    for ch in input.chars() {
+        result = (result + (ch as u8 as usize)) * 17;
+        result &= 255;
+    }
+    result
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let input = input.trim_end();
+        Ok(Self {
+            input: input.to_string(),
+            ops: parse_ops(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        hash_csv(&self.input)
+    }
+
+    fn part2(&self) -> usize {
+        hash_map(&self.ops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(hash_csv("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"), 1320);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(hash_map(&parse_ops("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7").unwrap()), 145);
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::point::Dir;
+use crate::solution::Solution;
+
+pub struct Day16 {
+    map: Grid<Tile>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Empty,
+    /// `/`
+    Mirror,
+    /// `\`
+    BackMirror,
+    /// `|`
+    VerticalSplitter,
+    /// `-`
+    HorizontalSplitter,
+}
+
+impl Tile {
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '.' => Some(Tile::Empty),
+            '/' => Some(Tile::Mirror),
+            '\\' => Some(Tile::BackMirror),
+            '|' => Some(Tile::VerticalSplitter),
+            '-' => Some(Tile::HorizontalSplitter),
+            _ => None,
+        }
+    }
+
+    /// The directions a beam travelling in `dir` leaves this tile in.
+    pub fn deflect(self, dir: Dir) -> Vec<Dir> {
+        match (self, dir) {
+            (Tile::Empty, _) => vec![dir],
+            (Tile::Mirror, Dir::East | Dir::West) => vec![dir.turn_left()],
+            (Tile::Mirror, Dir::North | Dir::South) => vec![dir.turn_right()],
+            (Tile::BackMirror, Dir::East | Dir::West) => vec![dir.turn_right()],
+            (Tile::BackMirror, Dir::North | Dir::South) => vec![dir.turn_left()],
+            (Tile::VerticalSplitter, Dir::North | Dir::South) => vec![dir],
+            (Tile::VerticalSplitter, Dir::East | Dir::West) => vec![Dir::North, Dir::South],
+            (Tile::HorizontalSplitter, Dir::East | Dir::West) => vec![dir],
+            (Tile::HorizontalSplitter, Dir::North | Dir::South) => vec![Dir::West, Dir::East],
+        }
+    }
+}
+
+/// Parses the contraption layout.
+pub fn parse_map(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, Tile::from_char)
+}
+
+/// The number of tiles a beam entering at `pos` heading `dir` passes through.
+pub fn calc_energized(map: &Grid<Tile>, pos: Pos, dir: Dir) -> usize {
+    let mut tiles = HashSet::new();
+    propagate(pos, dir, &mut tiles, map);
+    tiles.into_iter().map(|(pos, _)| pos).collect::<HashSet<_>>().len()
+}
+
+/// The most tiles any beam entering from an edge energizes.
+pub fn calc_tiles(map: &Grid<Tile>) -> usize {
+    let (max_x, max_y) = (map.width() - 1, map.height() - 1);
+
+    let mut max = 0;
+
+    let mut posdirs: Vec<(Pos, Dir)> = vec![];
+    for y in 0..map.height() {
+        posdirs.push(((0, y), Dir::East));
+        posdirs.push(((max_x, y), Dir::West));
+    }
+    for x in 0..map.width() {
+        posdirs.push(((x, 0), Dir::South));
+        posdirs.push(((x, max_y), Dir::North));
+    }
+    for (pos, dir) in posdirs {
+        let res = calc_energized(map, pos, dir);
+        if res > max {
+            max = res;
+        }
+    }
+    max
+}
+
+fn propagate(pos: Pos, dir: Dir, tiles: &mut HashSet<(Pos, Dir)>, map: &Grid<Tile>) {
+    if tiles.contains(&(pos, dir)) {
+        return;
+    }
+
+    tiles.insert((pos, dir));
+    for d in map[pos].deflect(dir) {
+        if let Some(next) = map.step(pos, d) {
+            propagate(next, d, tiles, map);
+        }
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: parse_map(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        calc_energized(&self.map, (0, 0), Dir::East)
+    }
+
+    fn part2(&self) -> usize {
+        calc_tiles(&self.map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#;
+        let map = parse_map(input).unwrap();
+        assert_eq!(calc_energized(&map, (0, 0), Dir::East), 46);
+        assert_eq!(calc_tiles(&map), 51);
+    }
+}
\ No newline at end of file
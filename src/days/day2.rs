@@ -0,0 +1,437 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, multispace0};
+use nom::combinator::{cut, map};
+use nom::error::context;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, tuple};
+
+use crate::error::ParseError;
+use crate::parse::{parse_all, records, unsigned, IResult};
+use crate::solution::Solution;
+
+pub struct Day2 {
+    games: Vec<Game>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub grabs: Vec<Grab>
+}
+
+/// A cube color. The puzzle's three get their own variants and any other name is kept as-is.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+    Other(OtherColor),
+}
+
+/// The name of a color other than red, green or blue. Only [`Color::named`] makes one, so the
+/// same color can't end up as two different keys.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OtherColor(String);
+
+impl Color {
+    pub fn named(name: &str) -> Color {
+        match name {
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "blue" => Color::Blue,
+            _ => Color::Other(OtherColor(name.to_string())),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Blue => "blue",
+            Color::Other(OtherColor(name)) => name,
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Color::named(s))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// One handful of cubes shown from the bag.
+#[derive(Debug, PartialEq)]
+pub struct Grab {
+    pub amounts: Vec<CubeAmount>
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CubeAmount {
+    pub color: Color,
+    pub amount: usize,
+}
+
+impl CubeAmount {
+    pub fn new(color: Color, amount: usize) -> Self {
+        Self {
+            color,
+            amount
+        }
+    }
+}
+
+fn parse_color(input: &str) -> IResult<'_, Color> {
+    context("expected a color", map(alpha1, Color::named))(input)
+}
+
+fn parse_grab_amount(input: &str) -> IResult<'_, CubeAmount> {
+    map(tuple((
+        context("expected a number of cubes", unsigned),
+        tag(" "),
+        parse_color,
+    )), |(amount, _, color)| {
+        CubeAmount {
+            color,
+            amount
+        }})(input)
+}
+
+fn parse_grab_amounts(input: &str) -> IResult<'_, Vec<CubeAmount>> {
+    separated_list1(tag(", "), cut(parse_grab_amount))(input)
+}
+
+fn parse_grabs(input: &str) -> IResult<'_, Vec<Grab>> {
+    separated_list1(pair(tag(";"), multispace0), cut(map(parse_grab_amounts, |amounts| {
+        Grab {
+            amounts
+        }
+    })))(input)
+}
+
+fn parse_game(input: &str) -> IResult<'_, Game> {
+    map(tuple((context("expected `Game <id>: `", delimited(tag("Game "), unsigned, tag(": "))), parse_grabs)), |(id, grabs)| Game {
+        id,
+        grabs,
+    })(input)
+}
+
+fn games(input: &str) -> IResult<'_, Vec<Game>> {
+    records(cut(parse_game))(input)
+}
+
+/// Parses one `Game <id>: <grabs>` record per line.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse_all(input, games)
+}
+
+/// How many cubes of each color a bag holds. Colors it doesn't mention, it has none of.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bag {
+    cubes: BTreeMap<Color, usize>,
+}
+
+impl Bag {
+    pub fn get(&self, color: &Color) -> usize {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &Color> {
+        self.cubes.keys()
+    }
+
+    /// The number of cubes in the bag.
+    pub fn total(&self) -> usize {
+        self.cubes.values().sum()
+    }
+
+    /// The product of the number of cubes of each color.
+    pub fn power(&self) -> usize {
+        self.cubes.values().product()
+    }
+
+    /// Whether this bag has at least as many cubes of every color as `other`.
+    pub fn contains(&self, other: &Bag) -> bool {
+        other.cubes.iter().all(|(color, amount)| *amount <= self.get(color))
+    }
+
+    /// Whether every grab of `game` could have come from this bag.
+    pub fn allows(&self, game: &Game) -> bool {
+        self.contains(&game.minimal_bag())
+    }
+
+    /// The smallest bag containing both this one and `other`.
+    pub fn union(mut self, other: &Bag) -> Bag {
+        for (color, amount) in &other.cubes {
+            let fewest = self.cubes.entry(color.clone()).or_insert(0);
+            *fewest = (*amount).max(*fewest);
+        }
+        self
+    }
+}
+
+impl FromIterator<CubeAmount> for Bag {
+    fn from_iter<I: IntoIterator<Item = CubeAmount>>(iter: I) -> Self {
+        let mut bag = Bag::default();
+        for cubes in iter {
+            *bag.cubes.entry(cubes.color).or_insert(0) += cubes.amount;
+        }
+        bag
+    }
+}
+
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    /// Parses cubes written as in a grab, like `12 red, 13 green, 14 blue`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(parse_all(s, parse_grab_amounts)?.into_iter().collect())
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.cubes.is_empty() {
+            return write!(f, "no cubes");
+        }
+        let cubes = self.cubes.iter().map(|(color, amount)| format!("{amount} {color}")).join(", ");
+        write!(f, "{cubes}")
+    }
+}
+
+impl Game {
+    /// The fewest cubes of each color the bag must have held for this game to be possible.
+    pub fn minimal_bag(&self) -> Bag {
+        self.grabs.iter().fold(Bag::default(), |bag, grab| {
+            bag.union(&grab.amounts.iter().cloned().collect())
+        })
+    }
+}
+
+/// The games that were possible with `bag`.
+pub fn feasible_games<'a>(games: &'a [Game], bag: &Bag) -> Vec<&'a Game> {
+    games.iter().filter(|game| bag.allows(game)).collect()
+}
+
+/// The games that were possible if the bag only held `available_cubes`. Colors missing from the
+/// bag count as none available.
+pub fn valid_games(games: &[Game], available_cubes: Vec<CubeAmount>) -> Vec<&Game> {
+    feasible_games(games, &available_cubes.into_iter().collect())
+}
+
+/// The power (product of the fewest cubes of each color needed) of every game.
+pub fn calc_powers(games: &[Game]) -> Vec<usize> {
+    games.iter().map(|game| game.minimal_bag().power()).collect()
+}
+
+/// The bag with the fewest cubes in total that allows at least `k` of `games`, or `None` if
+/// there aren't `k` games.
+///
+/// Each color's amount in the best bag is some game's need for it, so this tries those amounts
+/// color by color, which is exponential in the number of colors but fine for a handful.
+pub fn smallest_bag(games: &[Game], k: usize) -> Option<Bag> {
+    if k > games.len() {
+        return None;
+    }
+    let needs = games.iter().map(Game::minimal_bag).collect::<Vec<_>>();
+    let colors = needs.iter().flat_map(Bag::colors).cloned().collect::<BTreeSet<_>>().into_iter().collect::<Vec<_>>();
+    if k == 0 || colors.is_empty() {
+        return Some(Bag::default());
+    }
+
+    let mut best = None;
+    search_bags(&needs.iter().collect::<Vec<_>>(), &colors, k, &mut vec![], &mut best);
+    best.map(|(_, amounts)| colors.into_iter().zip(amounts).map(|(color, amount)| CubeAmount::new(color, amount)).collect())
+}
+
+/// Picks an amount for `colors[chosen.len()]` given the amounts `chosen` for the colors before
+/// it, keeping only the `candidates` those allow. `best` is the smallest total found so far.
+fn search_bags(candidates: &[&Bag], colors: &[Color], k: usize, chosen: &mut Vec<usize>, best: &mut Option<(usize, Vec<usize>)>) {
+    let color = &colors[chosen.len()];
+    let mut amounts = candidates.iter().map(|bag| bag.get(color)).collect::<Vec<_>>();
+    amounts.sort_unstable();
+    let so_far = chosen.iter().sum::<usize>();
+
+    if chosen.len() + 1 == colors.len() {
+        // The last color only needs enough for the k least demanding candidates.
+        let total = so_far + amounts[k - 1];
+        if best.as_ref().is_none_or(|(best, _)| total < *best) {
+            let mut amounts = chosen.clone();
+            amounts.push(total - so_far);
+            *best = Some((total, amounts));
+        }
+        return;
+    }
+
+    amounts.dedup();
+    for amount in amounts {
+        // Amounts only grow, so once this color alone can't beat the best, nothing after can.
+        if best.as_ref().is_some_and(|(best, _)| so_far + amount >= *best) {
+            break;
+        }
+        let allowed = candidates.iter().copied().filter(|bag| bag.get(color) <= amount).collect::<Vec<_>>();
+        if allowed.len() < k {
+            continue;
+        }
+        chosen.push(amount);
+        search_bags(&allowed, colors, k, chosen, best);
+        chosen.pop();
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            games: parse_games(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        valid_games(&self.games, vec![
+            CubeAmount::new(Color::Red, 12),
+            CubeAmount::new(Color::Green, 13),
+            CubeAmount::new(Color::Blue, 14),
+        ]).into_iter().map(|game| game.id as usize).sum()
+    }
+
+    fn part2(&self) -> usize {
+        calc_powers(&self.games).iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    const EXAMPLE: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+
+    #[test]
+    fn example1() {
+        let input = EXAMPLE;
+        let parsed = parse_games(input).unwrap();
+        assert_eq!(parsed.len(), 5);
+        assert_eq!(parsed[1].id, 2);
+        assert_eq!(parsed[1].grabs.len(), 3);
+        assert_eq!(parsed[1].grabs[1].amounts, vec![
+            CubeAmount::new(Color::Green, 3),
+            CubeAmount::new(Color::Blue, 4),
+            CubeAmount::new(Color::Red, 1),
+        ]);
+        assert_eq!(valid_games(&parsed, vec![
+            CubeAmount::new(Color::Red, 12),
+            CubeAmount::new(Color::Green, 13),
+            CubeAmount::new(Color::Blue, 14),
+        ]).into_iter().map(|game| game.id).sum::<u32>(), 8);
+
+        assert_eq!(calc_powers(&parsed), vec![
+            48, 12, 1560, 630, 36
+        ]);
+        assert_eq!(calc_powers(&parsed).iter().sum::<usize>(), 2286);
+    }
+
+    #[test]
+    fn any_palette() {
+        let input = "Game 1: 3 blue, 4 yellow\nGame 2: 1 purple, 2 yellow; 3 green\nGame 3: 5 purple";
+        let parsed = parse_games(input).unwrap();
+        assert_eq!(parsed[0].grabs[0].amounts[1], CubeAmount::new(Color::named("yellow"), 4));
+        assert_eq!(parsed[1].grabs[0].amounts[0].color.to_string(), "purple");
+
+        let bag = vec![
+            CubeAmount::new(Color::named("yellow"), 4),
+            CubeAmount::new(Color::named("purple"), 2),
+            CubeAmount::new(Color::Green, 3),
+        ];
+        // Game 1 needs blue, which this bag doesn't have at all.
+        assert_eq!(valid_games(&parsed, bag).into_iter().map(|game| game.id).collect::<Vec<_>>(), [2]);
+        assert_eq!(calc_powers(&parsed), vec![12, 6, 5]);
+    }
+
+    #[test]
+    fn known_names_are_normalised() {
+        assert_eq!(Color::named("red"), Color::Red);
+        assert_eq!("blue".parse::<Color>().unwrap(), Color::Blue);
+        assert!(matches!(Color::named("Red"), Color::Other(_)));
+
+        // A red made by name is the same key as a parsed one, so it counts towards the same total.
+        let bag: Bag = [CubeAmount::new(Color::named("red"), 12), CubeAmount::new(Color::Red, 1)].into_iter().collect();
+        assert_eq!(bag.to_string(), "13 red");
+        let games = parse_games("Game 1: 13 red").unwrap();
+        assert_eq!(feasible_games(&games, &bag).len(), 1);
+    }
+
+    #[test]
+    fn missing_color() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 ; 3 green";
+        let err = Day2::parse(input).err().unwrap();
+        assert_eq!(err.to_string(), "line 2, column 19: expected a color at \"; 3 green\"");
+    }
+
+    #[test]
+    fn bags() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let minimal = games.iter().map(|game| game.minimal_bag().to_string()).collect::<Vec<_>>();
+        assert_eq!(minimal[0], "4 red, 2 green, 6 blue");
+        assert_eq!(minimal[2], "20 red, 13 green, 6 blue");
+
+        let bag: Bag = "12 red, 13 green, 14 blue".parse().unwrap();
+        assert_eq!(feasible_games(&games, &bag).into_iter().map(|game| game.id).collect::<Vec<_>>(), [1, 2, 5]);
+        assert!(feasible_games(&games, &Bag::default()).is_empty());
+        assert_eq!("2 red, 3 red".parse::<Bag>().unwrap().to_string(), "5 red");
+        assert_eq!("12 red,".parse::<Bag>().unwrap_err().to_string(), "line 1, column 7: unexpected input at \",\"");
+    }
+
+    #[test]
+    fn smallest_bags() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let smallest = |k| smallest_bag(&games, k).map(|bag| bag.to_string());
+        assert_eq!(smallest(0).as_deref(), Some("no cubes"));
+        assert_eq!(smallest(1).as_deref(), Some("1 red, 3 green, 4 blue"));
+        assert_eq!(smallest(2).as_deref(), Some("4 red, 3 green, 6 blue"));
+        assert_eq!(smallest(3).as_deref(), Some("6 red, 3 green, 6 blue"));
+        assert_eq!(smallest(5).as_deref(), Some("20 red, 13 green, 15 blue"));
+        assert_eq!(smallest(6), None);
+    }
+
+    fn needs() -> impl Strategy<Value = Vec<Vec<usize>>> {
+        prop::collection::vec(prop::collection::vec(0usize..8, 3), 1..7)
+    }
+
+    proptest! {
+        #[test]
+        fn smallest_bag_matches_brute_force(needs in needs(), k in 0usize..8) {
+            let input = needs.iter().enumerate()
+                .map(|(idx, need)| format!("Game {}: {} red, {} green, {} blue", idx + 1, need[0], need[1], need[2]))
+                .join("\n");
+            let games = parse_games(&input).unwrap();
+
+            let brute_force = (0..games.len()).combinations(k)
+                .map(|chosen| chosen.into_iter().fold(Bag::default(), |bag, idx| bag.union(&games[idx].minimal_bag())).total())
+                .min();
+            let bag = smallest_bag(&games, k);
+            prop_assert_eq!(bag.as_ref().map(Bag::total), brute_force);
+            if let Some(bag) = bag {
+                prop_assert!(feasible_games(&games, &bag).len() >= k);
+            }
+        }
+    }
+}
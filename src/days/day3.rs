@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::solution::Solution;
+
+pub struct Day3 {
+    numbers: Vec<PartNumber>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PartNumber {
+    pub value: usize,
+    /// The symbols touching any digit of the number, including diagonally.
+    pub symbols: Vec<(Pos, char)>,
+}
+
+fn is_symbol(ch: char) -> bool {
+    ch != '.' && !ch.is_ascii_digit()
+}
+
+/// Every number in the schematic, in reading order.
+pub fn find_numbers(schematic: &Grid<char>) -> Vec<PartNumber> {
+    let mut numbers = vec![];
+    for (y, row) in schematic.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            if !row[x].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+
+            let mut value = 0;
+            let mut symbols = vec![];
+            while let Some(digit) = row.get(x).and_then(|ch| ch.to_digit(10)) {
+                value = value * 10 + digit as usize;
+                for pos in schematic.neighbours8((x, y)) {
+                    let ch = schematic[pos];
+                    if is_symbol(ch) && !symbols.contains(&(pos, ch)) {
+                        symbols.push((pos, ch));
+                    }
+                }
+                x += 1;
+            }
+            numbers.push(PartNumber {
+                value,
+                symbols,
+            });
+        }
+    }
+    numbers
+}
+
+/// The sum of the numbers touching at least one symbol.
+pub fn sum_part_numbers(numbers: &[PartNumber]) -> usize {
+    numbers.iter()
+        .filter(|number| !number.symbols.is_empty())
+        .map(|number| number.value)
+        .sum()
+}
+
+/// The sum of the products of the two numbers touching each `*` that touches exactly two.
+pub fn sum_gear_ratios(numbers: &[PartNumber]) -> usize {
+    let mut gears: HashMap<Pos, Vec<usize>> = HashMap::new();
+    for number in numbers {
+        for (pos, ch) in &number.symbols {
+            if *ch == '*' {
+                gears.entry(*pos).or_default().push(number.value);
+            }
+        }
+    }
+
+    gears.values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| numbers[0] * numbers[1])
+        .sum()
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let schematic = Grid::parse(input, |ch| ch.is_ascii_graphic().then_some(ch))?;
+        Ok(Self {
+            numbers: find_numbers(&schematic),
+        })
+    }
+
+    fn part1(&self) -> usize {
+        sum_part_numbers(&self.numbers)
+    }
+
+    fn part2(&self) -> usize {
+        sum_gear_ratios(&self.numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+        let day = Day3::parse(input).unwrap();
+        assert_eq!(day.numbers.len(), 10);
+        assert_eq!(day.numbers[1], PartNumber { value: 114, symbols: vec![] });
+        assert_eq!(day.numbers[2], PartNumber { value: 35, symbols: vec![((3, 1), '*')] });
+        assert_eq!(day.part1(), 4361);
+        assert_eq!(day.part2(), 467835);
+    }
+
+    #[test]
+    fn number_at_end_of_row() {
+        let input = "..12\n#..*\n5...";
+        let day = Day3::parse(input).unwrap();
+        assert_eq!(day.numbers.iter().map(|number| number.value).collect::<Vec<_>>(), vec![12, 5]);
+        assert_eq!(day.part1(), 17);
+        assert_eq!(day.part2(), 0);
+    }
+}
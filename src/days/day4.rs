@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, BTreeSet};
+use nom::bytes::complete::tag;
+use nom::character::complete::space1;
+use nom::combinator::map;
+use nom::error::context;
+use nom::sequence::{delimited, preceded, tuple};
+
+use crate::error::ParseError;
+use crate::parse::{parse_lines, spaced, unsigned, IResult};
+use crate::solution::Solution;
+
+pub struct Day4 {
+    cards: Vec<Card>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Card {
+    pub id: u32,
+    pub winning: Vec<u32>,
+    pub have: Vec<u32>
+}
+
+impl Card {
+    pub fn num_matching(&self) -> u32 {
+        self.have.iter().filter(|have| self.winning.contains(have)).collect::<Vec<_>>().len() as u32
+    }
+
+    pub fn score(&self) -> usize {
+        let mut score = 0;
+        for have in &self.have {
+            if self.winning.contains(have) {
+                if score == 0 {
+                    score = 1;
+                } else {
+                    score *= 2;
+                }
+            }
+        }
+        score
+    }
+}
+
+fn parse_card(input: &str) -> IResult<'_, Card> {
+    map(tuple((
+                  context("expected `Card <id>: `", delimited(tuple((tag("Card"), space1)), unsigned, tuple((tag(":"), space1)))),
+                  spaced(unsigned),
+                  preceded(context("expected ` | `", tuple((tag(" |"), space1))), spaced(unsigned)),
+              )), |(id, winning, have)| {
+        Card {
+            id,
+            winning,
+            have,
+        }
+    })(input)
+}
+
+/// Parses one `Card <id>: <winning> | <have>` record per line.
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lines(input, parse_card)
+}
+
+/// The total number of scratchcards held once every won copy has been scratched.
+pub fn get_num_winning_cards(cards: &[Card]) -> usize {
+    let cards = cards.iter().map(|card| (card.id, card.clone())).collect::<BTreeMap<_, _>>();
+    let mut won_ids: Vec<u32> = vec![];
+    for card_id in cards.keys() {
+        won_ids.push(*card_id);
+    }
+    let mut idx = 0;
+    while idx < won_ids.len() {
+        let card_id = *won_ids.get(idx).unwrap();
+        let card = cards.get(&card_id).expect("Day4::parse checks every won card is on the table");
+        let num_matching = card.num_matching();
+        for i in 0..num_matching {
+            won_ids.push(card_id + i + 1);
+        }
+        idx += 1;
+    }
+    won_ids.len()
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let cards = parse_cards(input)?;
+        // Part 2 looks up every card won, so they must all be on the table.
+        let ids = cards.iter().map(|card| card.id).collect::<BTreeSet<_>>();
+        for (idx, (card, line)) in cards.iter().zip(input.lines()).enumerate() {
+            if let Some(missing) = (card.id + 1..=card.id + card.num_matching()).find(|id| !ids.contains(id)) {
+                return Err(ParseError::new(idx + 1, 1, line, format!("card {} wins a copy of card {missing}, which isn't on the table", card.id)));
+            }
+        }
+        Ok(Self {
+            cards,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.cards.iter().map(|card| card.score()).sum()
+    }
+
+    fn part2(&self) -> usize {
+        get_num_winning_cards(&self.cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let input = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+        let cards = parse_cards(input).unwrap();
+        assert_eq!(cards.iter().map(|card| card.score()).sum::<usize>(), 13);
+        assert_eq!(get_num_winning_cards(&cards), 30);
+    }
+
+    #[test]
+    fn wins_past_the_last_card() {
+        let err = Day4::parse("Card 1: 5 6 | 5 7\nCard 2: 5 | 5").err().unwrap();
+        assert_eq!(err.to_string(), "line 2, column 1: card 2 wins a copy of card 3, which isn't on the table at \"Card 2: 5 | 5\"");
+    }
+}
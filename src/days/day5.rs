@@ -0,0 +1,231 @@
+use nom::bytes::complete::{tag, take_while1};
+use nom::character::complete::{line_ending, space1};
+use nom::combinator::{cut, map};
+use nom::error::{context, VerboseError, VerboseErrorKind};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, preceded, separated_pair, terminated, tuple};
+
+use crate::error::ParseError;
+use crate::interval::{Interval, RangeSet};
+use crate::parse::{parse_all, records, spaced, unsigned, IResult};
+use crate::solution::Solution;
+
+pub struct Day5 {
+    almanac: Almanac,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<Map>,
+}
+
+/// One `<from>-to-<to> map:` section, applied in the order it appears in the almanac.
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    pub mappings: Vec<Mapping>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Mapping {
+    pub dest: u64,
+    pub source: u64,
+    pub len: u64,
+}
+
+impl Map {
+    pub fn apply(&self, value: u64) -> u64 {
+        self.mappings.iter()
+            .find(|m| (m.source..m.source + m.len).contains(&value))
+            .map_or(value, |m| value - m.source + m.dest)
+    }
+
+    /// Maps whole ranges at once, splitting them wherever they straddle a mapping's edge.
+    pub fn apply_ranges(&self, ranges: &RangeSet) -> RangeSet {
+        let table = self.mappings.iter()
+            .map(|m| (Interval::new(m.source as i64, (m.source + m.len) as i64), m.dest as i64 - m.source as i64))
+            .collect::<Vec<_>>();
+        ranges.split_map(&table)
+    }
+}
+
+/// The largest value a range can end at, since ranges are mapped as `i64` intervals.
+const MAX_END: u64 = i64::MAX as u64;
+
+fn parse_mapping(input: &str) -> IResult<'_, Mapping> {
+    let (rest, mapping) = map(tuple((unsigned, preceded(space1, unsigned), preceded(space1, unsigned))), |(dest, source, len)| Mapping {
+        dest,
+        source,
+        len,
+    })(input)?;
+    // Past the numbers, so fail outright rather than let the list of mappings end here.
+    if mapping.dest.max(mapping.source).checked_add(mapping.len).is_none_or(|end| end > MAX_END) {
+        let errors = vec![(input, VerboseErrorKind::Context("mapping ends past i64::MAX"))];
+        return Err(nom::Err::Failure(VerboseError { errors }));
+    }
+    Ok((rest, mapping))
+}
+
+fn parse_map(input: &str) -> IResult<'_, Map> {
+    let name = take_while1(|ch: char| ch.is_ascii_lowercase() || ch == '-');
+    map(preceded(
+        pair(context("expected `<from>-to-<to> map:`", terminated(name, tag(" map:"))), line_ending),
+        cut(records(parse_mapping)),
+    ), |mappings| Map {
+        mappings,
+    })(input)
+}
+
+fn almanac(input: &str) -> IResult<'_, Almanac> {
+    map(separated_pair(
+        context("expected `seeds: `", preceded(tag("seeds: "), spaced(unsigned))),
+        pair(line_ending, line_ending),
+        separated_list1(pair(line_ending, line_ending), cut(parse_map)),
+    ), |(seeds, maps)| Almanac {
+        seeds,
+        maps,
+    })(input)
+}
+
+/// Parses the `seeds:` line followed by the `<from>-to-<to> map:` sections.
+pub fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    parse_all(input, almanac)
+}
+
+/// The lowest location any of the seeds maps to.
+pub fn lowest_location(almanac: &Almanac) -> u64 {
+    almanac.seeds.iter()
+        .map(|seed| almanac.maps.iter().fold(*seed, |value, map| map.apply(value)))
+        .min()
+        .unwrap()
+}
+
+/// The lowest location any seed maps to, reading the seeds as `<start> <length>` pairs. Fails if
+/// they don't pair up or every range is empty.
+pub fn lowest_location_for_ranges(almanac: &Almanac) -> Result<u64, ParseError> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(ParseError::new(1, 1, "", "expected pairs of seed ranges"));
+    }
+    let seeds = almanac.seeds.chunks_exact(2)
+        .map(|pair| match pair[0].checked_add(pair[1]) {
+            Some(end) if end <= MAX_END => Ok(pair[0] as i64..end as i64),
+            _ => Err(ParseError::new(1, 1, "", "seed range ends past i64::MAX")),
+        })
+        .collect::<Result<_, _>>()?;
+    let lowest = almanac.maps.iter()
+        .fold(seeds, |ranges, map| map.apply_ranges(&ranges))
+        .min()
+        .ok_or_else(|| ParseError::new(1, 1, "", "every seed range is empty"))?;
+    Ok(lowest as u64)
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Answer = Result<u64, ParseError>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            almanac: parse_almanac(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u64, ParseError> {
+        Ok(lowest_location(&self.almanac))
+    }
+
+    fn part2(&self) -> Result<u64, ParseError> {
+        lowest_location_for_ranges(&self.almanac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+    use super::*;
+
+    const EXAMPLE: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#;
+
+    #[test]
+    fn example() {
+        let day = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(day.almanac.seeds, vec![79, 14, 55, 13]);
+        assert_eq!(day.almanac.maps.len(), 7);
+        assert_eq!(day.almanac.maps[0].apply(79), 81);
+        assert_eq!(day.almanac.maps[0].apply(14), 14);
+        assert_eq!(day.part1().unwrap(), 35);
+        assert_eq!(day.part2().unwrap(), 46);
+    }
+
+    #[test]
+    fn split_ranges() {
+        let map = Map {
+            mappings: vec![Mapping { dest: 100, source: 10, len: 5 }],
+        };
+        let ranges = |ranges: &[Range<i64>]| ranges.iter().cloned().collect::<RangeSet>();
+        assert_eq!(map.apply_ranges(&ranges(&[0..20, 40..45])), ranges(&[100..105, 0..10, 15..20, 40..45]));
+        assert_eq!(map.apply_ranges(&ranges(&[11..13, 20..30])), ranges(&[101..103, 20..30]));
+    }
+
+    #[test]
+    fn unpaired_or_empty_seeds() {
+        // Only part 2 reads the seeds as ranges, so part 1 still answers.
+        let day = Day5::parse(&EXAMPLE.replace("seeds: 79 14 55 13", "seeds: 79 14 55")).unwrap();
+        assert_eq!(day.part1().unwrap(), 43);
+        assert_eq!(day.part2().unwrap_err().to_string(), "line 1, column 1: expected pairs of seed ranges");
+
+        let day = Day5::parse(&EXAMPLE.replace("seeds: 79 14 55 13", "seeds: 5 0")).unwrap();
+        assert_eq!(day.part2().unwrap_err().to_string(), "line 1, column 1: every seed range is empty");
+    }
+
+    #[test]
+    fn too_large() {
+        let input = EXAMPLE.replace("39 0 15", "39 9223372036854775800 15");
+        let err = Day5::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "line 10, column 1: mapping ends past i64::MAX at \"39 9223372036854775800 15\"");
+        let input = EXAMPLE.replace("39 0 15", "39 0 18446744073709551615");
+        assert!(Day5::parse(&input).is_err());
+
+        let day = Day5::parse(&EXAMPLE.replace("seeds: 79 14 55 13", "seeds: 9223372036854775800 14")).unwrap();
+        assert_eq!(day.part2().unwrap_err().to_string(), "line 1, column 1: seed range ends past i64::MAX");
+    }
+
+    #[test]
+    fn bad_mapping() {
+        let input = EXAMPLE.replace("39 0 15", "39 0 x");
+        let err = Day5::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "line 10, column 1: unexpected input at \"39 0 x\"");
+    }
+}
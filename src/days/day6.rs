@@ -0,0 +1,95 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::space0;
+use nom::sequence::{preceded, tuple};
+
+use crate::error::ParseError;
+use crate::parse::{parse_all, spaced, unsigned, IResult};
+use crate::solution::Solution;
+
+pub struct Day6 {
+    races: Vec<Race>,
+    kerned_races: Vec<Race>,
+}
+
+#[derive(Debug)]
+pub struct Race {
+    pub time: usize,
+    /// The record distance to beat.
+    pub distance: usize,
+}
+
+fn parse_line<'a>(prefix: &str, input: &'a str) -> IResult<'a, Vec<usize>> {
+    preceded(tuple((tag(prefix), space0)), spaced(unsigned))(input)
+}
+
+/// Parses the `Time:` and `Distance:` lines into races.
+pub fn parse_races(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = input.lines();
+    let mut parse_next = |idx: usize, prefix: &str| {
+        let line = lines.next().ok_or_else(|| ParseError::new(idx, 1, "", format!("expected a `{prefix}` line")))?;
+        parse_all(line, |line| parse_line(prefix, line)).map_err(|err| err.on_line(idx))
+    };
+    let time = parse_next(1, "Time:")?;
+    let distance = parse_next(2, "Distance:")?;
+    if time.len() != distance.len() {
+        return Err(ParseError::new(2, 1, "", format!("expected {} distances, found {}", time.len(), distance.len())));
+    }
+
+    Ok(time.into_iter().zip(distance).map(|(time, distance)| Race {
+        time, distance
+    }).collect())
+}
+
+/// The number of ways to beat the record in each race.
+pub fn get_options(races: &[Race]) -> Vec<usize> {
+    let mut options = vec![];
+    for race in races {
+        let mut valid = 0;
+        for button_time in 0..race.time {
+            let total_distance = (race.time - button_time) * button_time;
+            if total_distance > race.distance {
+                valid += 1;
+            }
+        }
+        options.push(valid);
+    }
+    options
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let races = parse_races(input)?;
+        let input = input.replace(' ', "");
+        log::debug!("de-kerned input: {input:?}");
+        let kerned_races = parse_races(&input)?;
+        Ok(Self {
+            races,
+            kerned_races,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        get_options(&self.races).iter().product()
+    }
+
+    fn part2(&self) -> usize {
+        get_options(&self.kerned_races).iter().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let input = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+        let races = parse_races(input).unwrap();
+        assert_eq!(get_options(&races).iter().product::<usize>(), 288);
+        assert_eq!(Day6::parse(input).unwrap().part2(), 71503);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,308 @@
+use std::cmp::Ordering;
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+pub struct Day7 {
+    hands: Vec<(Hand, usize)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hand {
+    pub hand: [Card; 5]
+}
+
+impl TryFrom<&str> for Hand {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let cards = value.chars()
+            .enumerate()
+            .map(|(idx, ch)| Card::try_from(ch).map_err(|message| {
+                ParseError::new(1, idx + 1, value.chars().skip(idx).collect::<String>(), message)
+            }))
+            .collect::<Result<Vec<_>, _>>()?;
+        let hand = cards.try_into().map_err(|cards: Vec<Card>| {
+            ParseError::new(1, 1, value, format!("expected 5 cards, found {}", cards.len()))
+        })?;
+        Ok(Self { hand })
+    }
+}
+
+/// Every card a joker can stand in for. Jacks are all jokers by then, so they're left out.
+const JOKER_SUBSTITUTES: [Card; 12] = [
+    Card::Two, Card::Three, Card::Four, Card::Five, Card::Six, Card::Seven,
+    Card::Eight, Card::Nine, Card::T, Card::Q, Card::K, Card::A,
+];
+
+impl Hand {
+    /// Treats every jack in the hand as a joker.
+    pub fn with_jokers(&self) -> Self {
+        let mut hand = self.hand.clone();
+        for card in &mut hand {
+            if *card == Card::J {
+                *card = Card::Joker;
+            }
+        }
+        Self { hand }
+    }
+
+    /// The best type this hand can make, with any jokers standing in for other cards.
+    pub fn ty(&self) -> HandType {
+        let num_jokers = self.hand.iter().filter(|c| **c == Card::Joker).count();
+        let non_jokers = std::iter::repeat_n(JOKER_SUBSTITUTES.iter(), num_jokers).multi_cartesian_product().collect::<Vec<_>>();
+        let cards = self.hand.clone();
+
+        let mut group_maps = vec![];
+        group_maps.push(cards.clone().into_iter().into_group_map_by(|card| card.clone()));
+        for non_joker in &non_jokers {
+            let mut nj_idx = 0;
+            let mut cards = cards.clone();
+            for card in &mut cards {
+                if *card == Card::Joker {
+                    *card = non_joker[nj_idx].clone();
+                    nj_idx += 1;
+                }
+            }
+            log::trace!("testing jokers as {cards:?}");
+            group_maps.push(cards.clone().into_iter().into_group_map_by(|card| card.clone()));
+        }
+
+        for group_map in &group_maps {
+            if group_map.iter().any(|(_, group)| group.len() == 5) {
+                return HandType::FiveOfAKind;
+            }
+        }
+        for group_map in &group_maps {
+            if group_map.iter().any(|(_, group)| group.len() == 4) {
+                return HandType::FourOfAKind;
+            }
+        }
+        for group_map in &group_maps {
+            if group_map.iter().any(|(_, group)| group.len() == 3) && group_map.iter().any(|(_, group)| group.len() == 2) {
+                return HandType::FullHouse;
+            }
+        }
+        for group_map in &group_maps {
+            if group_map.iter().any(|(_, group)| group.len() == 3) {
+                return HandType::ThreeOfAKind;
+            }
+        }
+        for group_map in &group_maps {
+            if group_map.iter().filter(|(_, group)| group.len() == 2).collect::<Vec<_>>().len() == 2 {
+                return HandType::TwoPair;
+            }
+        }
+        for group_map in &group_maps {
+            if group_map.iter().filter(|(_, group)| group.len() == 2).collect::<Vec<_>>().len() == 1 {
+                return HandType::OnePair;
+            }
+        }
+
+        for group_map in &group_maps {
+            let distinct = group_map.iter().all(|(_, group)| group.len() == 1);
+            if distinct {
+                return HandType::HighCard;
+            }
+        }
+
+        HandType::Nothing
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+pub enum HandType {
+    Nothing,
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.ty() > other.ty() {
+            return Some(Ordering::Greater);
+        }
+        if self.ty() < other.ty() {
+            return Some(Ordering::Less);
+        }
+
+        for i in 0..5 {
+            if self.hand[i] > other.hand[i] {
+                return Some(Ordering::Greater);
+            }
+            if self.hand[i] < other.hand[i] {
+                return Some(Ordering::Less);
+            }
+        }
+
+        Some(Ordering::Equal)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Card {
+    A,
+    K,
+    Q,
+    J,
+    T,
+    Nine,
+    Eight,
+    Seven,
+    Six,
+    Five,
+    Four,
+    Three,
+    Two,
+    Joker,
+}
+
+impl TryFrom<char> for Card {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'A' => Card::A,
+            'K' => Card::K,
+            'Q' => Card::Q,
+            'J' => Card::J,
+            'T' => Card::T,
+            '9' => Card::Nine,
+            '8' => Card::Eight,
+            '7' => Card::Seven,
+            '6' => Card::Six,
+            '5' => Card::Five,
+            '4' => Card::Four,
+            '3' => Card::Three,
+            '2' => Card::Two,
+            _ => return Err(format!("unknown card {value:?}"))
+        })
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.score().partial_cmp(&other.score())
+    }
+}
+
+impl Card {
+    pub fn score(&self) -> usize {
+        match self {
+            Card::A => 14,
+            Card::K => 13,
+            Card::Q => 12,
+            Card::J => 11,
+            Card::T => 10,
+            Card::Nine => 9,
+            Card::Eight => 8,
+            Card::Seven => 7,
+            Card::Six => 6,
+            Card::Five => 5,
+            Card::Four => 4,
+            Card::Three => 3,
+            Card::Two => 2,
+            Card::Joker => 1,
+        }
+    }
+}
+
+/// Parses one `<hand> <bid>` pair per line.
+pub fn parse_input(input: &str) -> Result<Vec<(Hand, usize)>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let Some((hand, bid)) = line.split_once(' ') else {
+                return Err(ParseError::new(idx + 1, 1, line, "expected `<hand> <bid>`"));
+            };
+            let hand = Hand::try_from(hand).map_err(|err| err.on_line(idx + 1))?;
+            let bid = bid.parse::<usize>()
+                .map_err(|_| ParseError::new(idx + 1, line.len() - bid.len() + 1, bid, "expected a bid"))?;
+            Ok((hand, bid))
+        })
+        .collect()
+}
+
+/// The sum of every bid multiplied by its hand's rank from weakest to strongest.
+pub fn total_winnings(mut card_ranks: Vec<(Hand, usize)>) -> usize {
+    card_ranks.sort_by(|(a, _), (b, _ )| a.partial_cmp(b).unwrap());
+    card_ranks.iter().enumerate().map(|(idx, (_, b))| b * (idx + 1)).sum()
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            hands: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        total_winnings(self.hands.clone())
+    }
+
+    fn part2(&self) -> usize {
+        total_winnings(self.hands.iter().map(|(hand, bid)| (hand.with_jokers(), *bid)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> Hand {
+        Hand::try_from(cards).unwrap()
+    }
+
+    #[test]
+    fn algo() {
+        assert_eq!(hand("33332").ty(), HandType::FourOfAKind);
+        assert_eq!(hand("2AAAA").ty(), HandType::FourOfAKind);
+        assert!(hand("33332") > hand("2AAAA"));
+
+        assert_eq!(hand("77888").ty(), HandType::FullHouse);
+        assert_eq!(hand("77788").ty(), HandType::FullHouse);
+        assert!(hand("77888") > hand("77788"));
+
+        assert!(hand("KK677") > hand("KTJJT"));
+        assert!(hand("KK677") == hand("KK677"));
+        assert!(hand("KTJJT") < hand("KK677"));
+        assert!(hand("KTJJT") == hand("KTJJT"));
+        assert!(hand("32T3K") < hand("KK677"));
+        assert!(hand("32T3K") < hand("KTJJT"));
+    }
+
+    #[test]
+    fn algo_jokers() {
+        assert_eq!(hand("32T3K").with_jokers().ty(), HandType::OnePair);
+        assert_eq!(hand("KK677").with_jokers().ty(), HandType::TwoPair);
+        assert_eq!(hand("T55J5").with_jokers().ty(), HandType::FourOfAKind);
+        assert_eq!(hand("KTJJT").with_jokers().ty(), HandType::FourOfAKind);
+        assert_eq!(hand("QQQJA").with_jokers().ty(), HandType::FourOfAKind);
+    }
+
+    #[test]
+    fn example1() {
+        let input = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+        let day = Day7::parse(input).unwrap();
+        assert_eq!(day.part1(), 6440);
+        assert_eq!(day.part2(), 5905);
+    }
+
+    #[test]
+    fn unknown_card() {
+        let err = Day7::parse("32T3K 765\nT55X5 684").err().unwrap();
+        assert_eq!(err.to_string(), "line 2, column 4: unknown card 'X' at \"X5\"");
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::{BTreeMap, HashSet};
+use crate::error::ParseError;
+use crate::math;
+use crate::solution::Solution;
+
+pub struct Day8 {
+    network: Network,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Step {
+    L,
+    R,
+}
+
+pub struct Network {
+    pub steps: Vec<Step>,
+    /// Each node's left and right neighbours.
+    pub nodes: BTreeMap<String, (String, String)>,
+}
+
+/// Parses the step line followed by one `<node> = (<left>, <right>)` line per node.
+pub fn parse_network(input: &str) -> Result<Network, ParseError> {
+    let mut lines = input.lines();
+    let steps = lines.next().unwrap_or_default().chars()
+        .enumerate()
+        .map(|(idx, ch)| match ch {
+            'L' => Ok(Step::L),
+            'R' => Ok(Step::R),
+            _ => Err(ParseError::new(1, idx + 1, ch.to_string(), "expected `L` or `R`")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if steps.is_empty() {
+        return Err(ParseError::new(1, 1, "", "expected a list of steps"));
+    }
+    let _ = lines.next();
+
+    let mut nodes = BTreeMap::new();
+    let mut exits = vec![];
+    for (idx, line) in lines.enumerate() {
+        let node = line.split_once(" = ")
+            .and_then(|(node_id, exits)| Some((node_id, exits.strip_prefix('(')?.strip_suffix(')')?.split_once(", ")?)));
+        let Some((node_id, (l, r))) = node else {
+            return Err(ParseError::new(idx + 3, 1, line, "expected `<node> = (<left>, <right>)`"));
+        };
+        nodes.insert(node_id.to_string(), (l.to_string(), r.to_string()));
+        exits.extend([(idx + 3, line, l), (idx + 3, line, r)]);
+    }
+
+    for (line_number, line, exit) in exits {
+        if !nodes.contains_key(exit) {
+            let column = exit.as_ptr() as usize - line.as_ptr() as usize + 1;
+            return Err(ParseError::new(line_number, column, exit, "unknown node"));
+        }
+    }
+
+    Ok(Network {
+        steps,
+        nodes,
+    })
+}
+
+/// The steps needed to get from `AAA` to `ZZZ`, or with `ghosts`, for every node ending in
+/// `A` to be on a node ending in `Z` at the same time. Fails if there is no start or end node,
+/// as in the other part's examples.
+pub fn num_moves_to_reach(network: &Network, ghosts: bool) -> Result<u64, ParseError> {
+    let is_start = |node: &str| if ghosts { node.ends_with('A') } else { node == "AAA" };
+    let is_end = |node: &str| if ghosts { node.ends_with('Z') } else { node == "ZZZ" };
+    let (start, end) = if ghosts { ("no node ending in `A`", "no node ending in `Z`") } else { ("no `AAA` node", "no `ZZZ` node") };
+
+    let mut cur_poses = vec![];
+    for node in network.nodes.keys() {
+        if is_start(node) {
+            cur_poses.push(node.as_str());
+        }
+    }
+    log::debug!("start nodes: {cur_poses:?}");
+    if cur_poses.is_empty() {
+        return Err(ParseError::new(3, 1, "", start));
+    }
+    if !network.nodes.keys().any(|node| is_end(node)) {
+        return Err(ParseError::new(3, 1, "", end));
+    }
+
+    let mut total_steps = vec![];
+    for cur_pos in &mut cur_poses {
+        let from = *cur_pos;
+        let mut num_steps = 0;
+        // Once a node comes round again at the same point in the steps, the walk only repeats.
+        let mut seen = HashSet::new();
+        while !is_end(cur_pos) {
+            if !seen.insert((*cur_pos, num_steps % network.steps.len())) {
+                let goal = if ghosts { "a node ending in `Z`" } else { "`ZZZ`" };
+                return Err(ParseError::new(3, 1, "", format!("`{from}` never reaches {goal}")));
+            }
+            let (l, r) = &network.nodes[*cur_pos];
+            *cur_pos = match network.steps[num_steps % network.steps.len()] {
+                Step::L => l,
+                Step::R => r,
+            };
+            num_steps += 1;
+        }
+        total_steps.push(num_steps as u64);
+    }
+    log::debug!("steps until each reaches an end node: {total_steps:?}");
+    // There is at least one start, so `None` can only mean overflow.
+    math::lcm_all(total_steps).ok_or_else(|| ParseError::new(3, 1, "", "step counts overflow"))
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Answer = Result<u64, ParseError>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            network: parse_network(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u64, ParseError> {
+        num_moves_to_reach(&self.network, false)
+    }
+
+    fn part2(&self) -> Result<u64, ParseError> {
+        num_moves_to_reach(&self.network, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let input = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#;
+        let network = parse_network(input).unwrap();
+        assert_eq!(num_moves_to_reach(&network, false).unwrap(), 6);
+        assert_eq!(num_moves_to_reach(&network, true).unwrap(), 6);
+    }
+
+    #[test]
+    fn example2() {
+        let input = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+        let network = parse_network(input).unwrap();
+        assert_eq!(num_moves_to_reach(&network, true).unwrap(), 6);
+        // The ghosts' example has no `AAA`, so part 1 can't be answered from it.
+        assert_eq!(num_moves_to_reach(&network, false).unwrap_err().to_string(), "line 3, column 1: no `AAA` node");
+    }
+
+    #[test]
+    fn unreachable_end() {
+        let network = parse_network("L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(num_moves_to_reach(&network, false).unwrap_err().to_string(), "line 3, column 1: `AAA` never reaches `ZZZ`");
+        assert_eq!(num_moves_to_reach(&network, true).unwrap_err().to_string(), "line 3, column 1: `AAA` never reaches a node ending in `Z`");
+    }
+
+    #[test]
+    fn missing_end() {
+        let network = parse_network("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)").unwrap();
+        assert_eq!(num_moves_to_reach(&network, false).unwrap_err().to_string(), "line 3, column 1: no `ZZZ` node");
+        assert_eq!(num_moves_to_reach(&network, true).unwrap_err().to_string(), "line 3, column 1: no node ending in `Z`");
+    }
+
+    #[test]
+    fn unknown_node() {
+        let err = parse_network("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)").err().unwrap();
+        assert_eq!(err.to_string(), "line 3, column 8: unknown node at \"BBB\"");
+    }
+}
@@ -0,0 +1,86 @@
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::parse::{parse_lines, signed, spaced};
+use crate::solution::Solution;
+
+pub struct Day9 {
+    seqs: Vec<Vec<i64>>,
+}
+
+/// Parses one space-separated sequence of numbers per line.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_lines(input, spaced(signed))
+}
+
+/// The sequence followed by its rows of differences, down to the first constant row. A single
+/// number counts as constant.
+fn difference_rows(seq: Vec<i64>) -> Vec<Vec<i64>> {
+    let mut rows = vec![];
+    let mut row = seq;
+    while !row.iter().all_equal() {
+        let next = row.windows(2)
+            .map(|a| a[1] - a[0])
+            .collect::<Vec<_>>();
+        rows.push(row);
+        row = next;
+    }
+    rows.push(row);
+    rows
+}
+
+/// The next value of the sequence, extrapolated from its differences.
+pub fn predict_seq(seq: Vec<i64>) -> i64 {
+    difference_rows(seq).iter()
+        .filter_map(|row| row.last())
+        .sum()
+}
+
+/// The value before the start of the sequence, extrapolated from its differences.
+pub fn predict_rev_seq(seq: Vec<i64>) -> i64 {
+    difference_rows(seq).iter().rev()
+        .filter_map(|row| row.first())
+        .fold(0, |total, first| first - total)
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            seqs: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> i64 {
+        self.seqs.clone().into_iter().map(predict_seq).sum()
+    }
+
+    fn part2(&self) -> i64 {
+        self.seqs.clone().into_iter().map(predict_rev_seq).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let input = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+        let seqs = parse_input(input).unwrap();
+        let predictions = seqs.clone().into_iter().map(predict_seq).sum::<i64>();
+        assert_eq!(predictions, 114);
+        let predictions = seqs.into_iter().map(predict_rev_seq).sum::<i64>();
+        assert_eq!(predictions, 2);
+    }
+
+    #[test]
+    fn short_sequences() {
+        assert_eq!((predict_seq(vec![5]), predict_rev_seq(vec![5])), (5, 5));
+        assert_eq!((predict_seq(vec![1, 2]), predict_rev_seq(vec![1, 2])), (3, 0));
+        assert_eq!((predict_seq(vec![-3, -3]), predict_rev_seq(vec![-3, -3])), (-3, -3));
+    }
+}
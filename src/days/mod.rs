@@ -0,0 +1,63 @@
+//! One module per puzzle day. Each exposes a `DayN` type implementing [`Solution`] plus the
+//! parsers and algorithms it's built from.
+
+use crate::error::ParseError;
+use crate::solution::{self, Part, Solution, Timings};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+/// A solved day, with its `Solution` erased so all days can live in one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, Part) -> Result<String, ParseError>,
+    pub time: fn(&str) -> Result<Timings, ParseError>,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        number: S::DAY,
+        solve: solution::solve::<S>,
+        time: solution::time::<S>,
+    }
+}
+
+/// Every solved day, in order.
+pub const ALL: &[Day] = &[
+    day::<day1::Day1>(),
+    day::<day2::Day2>(),
+    day::<day3::Day3>(),
+    day::<day4::Day4>(),
+    day::<day5::Day5>(),
+    day::<day6::Day6>(),
+    day::<day7::Day7>(),
+    day::<day8::Day8>(),
+    day::<day9::Day9>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day14::Day14>(),
+    day::<day15::Day15>(),
+    day::<day16::Day16>(),
+];
+
+/// The day with the given number, if it has been solved.
+pub fn get(number: u8) -> Option<&'static Day> {
+    ALL.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+use nom::error::{ErrorKind, VerboseError, VerboseErrorKind};
+
+/// Why an input couldn't be parsed, and where.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text, up to the end of its line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// An error at the start of `rest`, which must be a suffix of `input` (as nom leaves it).
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let consumed = &input[..input.len() - rest.len()];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+        let text = rest.lines().next().unwrap_or_default();
+        Self::new(line, column, text, message)
+    }
+
+    /// Converts a nom error into a `ParseError` against the `input` that was being parsed. The
+    /// outermost `context` becomes the message.
+    pub fn from_nom(input: &str, err: nom::Err<VerboseError<&str>>) -> Self {
+        let errors = match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => err.errors,
+            nom::Err::Incomplete(_) => return Self::at(input, "", "unexpected end of input"),
+        };
+        let Some((rest, kind)) = errors.first() else {
+            return Self::at(input, input, "invalid input");
+        };
+
+        let message = errors.iter()
+            .rev()
+            .find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(context.to_string()),
+                _ => None,
+            })
+            .unwrap_or_else(|| match kind {
+                VerboseErrorKind::Char(ch) => format!("expected {ch:?}"),
+                VerboseErrorKind::Nom(ErrorKind::Eof) => "unexpected input".to_string(),
+                VerboseErrorKind::Nom(kind) => format!("invalid input ({})", kind.description()),
+                VerboseErrorKind::Context(context) => context.to_string(),
+            });
+        Self::at(input, rest, message)
+    }
+
+    /// Moves an error found while parsing a single line to line `line` of the whole input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}: ")?;
+        }
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " at {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of_rest() {
+        let input = "Game 1: 3 blue\nGame 2: 4 yellow, 1 red";
+        let err = ParseError::at(input, &input[25..], "unknown color");
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 11, "yellow, 1 red"));
+        assert_eq!(err.with_day(2).to_string(), "day 2: line 2, column 11: unknown color at \"yellow, 1 red\"");
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use crate::error::ParseError;
+use crate::point::{Dir, Point};
+
+/// A position in a grid, as `(x, y)` with `(0, 0)` in the top-left corner.
+pub type Pos = (usize, usize);
+
+/// A dense, rectangular 2D grid stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from `cells` in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {width}x{height} grid needs {} cells", width * height);
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self::new(width, height, cells)
+    }
+
+    /// Parses one cell per character, one row per line. Every row must be equally long.
+    pub fn parse(input: &str, mut parse_cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let mut line_width = 0;
+            for (x, ch) in line.chars().enumerate() {
+                let Some(cell) = parse_cell(ch) else {
+                    let text = line.chars().skip(x).collect::<String>();
+                    return Err(ParseError::new(y + 1, x + 1, text, format!("unexpected character {ch:?}")));
+                };
+                cells.push(cell);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::new(y + 1, 1, line, format!("expected {width} columns, found {line_width}")));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or_default(), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// The position `delta` away from `pos`, if that's still inside the grid.
+    pub fn offset(&self, (x, y): Pos, delta: Point) -> Option<Pos> {
+        let x = x.checked_add_signed(delta.x as isize)?;
+        let y = y.checked_add_signed(delta.y as isize)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// The next position from `pos` in `dir`, if that's still inside the grid.
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        self.offset(pos, dir.delta())
+    }
+
+    /// The up to four orthogonally adjacent positions inside the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The up to eight adjacent positions inside the grid, including diagonals.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Point::ORIGIN.neighbours8().into_iter().filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside a grid {} wide", self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(&mut f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its top-left to bottom-right diagonal.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, self.height - 1 - x)].clone())
+    }
+
+    /// Rotates the grid a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(self.width - 1 - y, x)].clone())
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters, accepting any character.
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input, Some)
+    }
+
+    /// Parses a grid of characters, rejecting any character not in `allowed`.
+    pub fn parse_only(input: &str, allowed: &[char]) -> Result<Self, ParseError> {
+        Self::parse(input, |ch| allowed.contains(&ch).then_some(ch))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{pos:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{pos:?} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::parse_chars(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse_only("..#\n.x#", &['.', '#']).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: unexpected character 'x' at \"x#\"");
+        let err = Grid::parse_chars("abc\nde").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected 3 columns, found 2 at \"de\"");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::parse_chars(INPUT).unwrap();
+        assert_eq!(grid.neighbours((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.step((2, 1), Dir::East), None);
+        assert_eq!(grid.step((2, 1), Dir::North), Some((2, 0)));
+        assert_eq!(grid.offset((2, 1), Point::new(-2, -1)), Some((0, 0)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::parse_chars(INPUT).unwrap();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(), vec!["ad", "be", "cf"]);
+        assert_eq!(grid.find(|ch| *ch == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = Grid::parse_chars(INPUT).unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug)]
+pub enum InputSource {
+    /// A single file, used as-is for whichever day is being solved.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+    /// A directory containing `day1.txt`, `day2.txt`, ...
+    Dir(PathBuf),
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    day: u8,
+    source: InputSource,
+    error: io::Error,
+}
+
+impl InputSource {
+    /// Picks an explicit `--input` argument over the inputs directory. A path of `-` means stdin.
+    pub fn new(input: Option<PathBuf>, inputs_dir: PathBuf) -> Self {
+        match input {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path),
+            None => InputSource::Dir(inputs_dir),
+        }
+    }
+
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+            InputSource::Dir(dir) => Some(dir.join(format!("day{day}.txt"))),
+        }
+    }
+
+    pub fn load(&self, day: u8) -> Result<String, InputError> {
+        let result = match self.path(day) {
+            Some(path) => std::fs::read_to_string(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|error| self.error(day, error))
+    }
+
+    /// Opens the input for reading in chunks, for inputs too large to load whole.
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        match self.path(day) {
+            Some(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(self.error(day, error)),
+            },
+            None => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    fn error(&self, day: u8, error: io::Error) -> InputError {
+        InputError {
+            day,
+            source: self.clone(),
+            error,
+        }
+    }
+}
+
+impl InputError {
+    /// Whether the input file doesn't exist, as opposed to existing but being unreadable.
+    pub fn is_missing(&self) -> bool {
+        self.error.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self.source.path(self.day);
+        match (&path, self.error.kind()) {
+            (Some(path), io::ErrorKind::NotFound) => write!(
+                f,
+                "no input for day {}: {} does not exist (use --input or --inputs-dir)",
+                self.day,
+                path.display()
+            ),
+            (Some(path), _) => write!(f, "could not read {}: {}", path.display(), self.error),
+            (None, _) => write!(f, "could not read stdin: {}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_file_in_dir() {
+        let source = InputSource::new(None, PathBuf::from("inputs"));
+        assert_eq!(source.path(12), Some(PathBuf::from("inputs/day12.txt")));
+    }
+
+    #[test]
+    fn dash_is_stdin() {
+        let source = InputSource::new(Some(PathBuf::from("-")), PathBuf::from("inputs"));
+        assert_eq!(source.path(12), None);
+    }
+
+    #[test]
+    fn missing_file() {
+        let source = InputSource::new(None, PathBuf::from("does-not-exist"));
+        let error = source.load(3).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no input for day 3: does-not-exist/day3.txt does not exist (use --input or --inputs-dir)"
+        );
+    }
+}
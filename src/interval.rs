@@ -0,0 +1,280 @@
+use std::fmt;
+use std::ops::Range;
+
+/// A half-open range of integers `start..end`. Empty when `start >= end`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// The number of integers in the interval.
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start)
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The overlap of both intervals, which may be empty.
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint, non-adjacent, non-empty intervals.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn count(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self.intervals.partition_point(|interval| interval.end <= value);
+        self.intervals.get(idx).is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn insert(&mut self, interval: impl Into<Interval>) {
+        *self = self.union(&RangeSet::from_iter([interval.into()]));
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        self.intervals.iter().chain(&other.intervals).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = vec![];
+        let (mut a, mut b) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let overlap = x.intersection(y);
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        RangeSet {
+            intervals,
+        }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = vec![];
+        let mut others = other.intervals.iter().peekable();
+        for interval in &self.intervals {
+            let mut start = interval.start;
+            while let Some(cut) = others.peek() {
+                if cut.end <= start {
+                    others.next();
+                    continue;
+                }
+                if cut.start >= interval.end {
+                    break;
+                }
+                if cut.start > start {
+                    intervals.push(Interval::new(start, cut.start));
+                }
+                start = cut.end;
+                if cut.end > interval.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        RangeSet {
+            intervals,
+        }
+    }
+
+    /// Moves every part of the set that lies in one of the `table`'s source intervals by that
+    /// entry's offset, leaving the rest in place. Where sources overlap, the first entry wins.
+    pub fn split_map(&self, table: &[(Interval, i64)]) -> RangeSet {
+        let mut remaining = self.clone();
+        let mut mapped = vec![];
+        for (source, offset) in table {
+            let source = RangeSet::from_iter([*source]);
+            mapped.extend(remaining.intersection(&source).intervals.iter().map(|interval| interval.shift(*offset)));
+            remaining = remaining.difference(&source);
+        }
+        mapped.extend(remaining.intervals);
+        mapped.into_iter().collect()
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted: Vec<Interval> = iter.into_iter().filter(|interval| !interval.is_empty()).collect();
+        sorted.sort_unstable();
+
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        Self {
+            intervals,
+        }
+    }
+}
+
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        iter.into_iter().map(Interval::from).collect()
+    }
+}
+
+impl fmt::Display for RangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (idx, interval) in self.intervals.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{interval}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use proptest::prelude::*;
+    use super::*;
+
+    fn naive(set: &RangeSet) -> HashSet<i64> {
+        set.intervals().iter().flat_map(|interval| interval.start..interval.end).collect()
+    }
+
+    fn range_set() -> impl Strategy<Value = RangeSet> {
+        prop::collection::vec((-50i64..50, 0i64..20), 0..6)
+            .prop_map(|ranges| ranges.into_iter().map(|(start, len)| start..start + len).collect())
+    }
+
+    #[test]
+    fn normalizes() {
+        let set: RangeSet = [5..8, 0..2, 2..3, 7..10, 20..20].into_iter().collect();
+        assert_eq!(set.intervals(), &[Interval::new(0, 3), Interval::new(5, 10)]);
+        assert_eq!(set.count(), 8);
+        assert_eq!((set.min(), set.max()), (Some(0), Some(9)));
+        assert_eq!(set.to_string(), "{0..3, 5..10}");
+    }
+
+    #[test]
+    fn split_by_table() {
+        let set: RangeSet = [0..20, 40..45].into_iter().collect();
+        let table = [(Interval::new(10, 15), 90), (Interval::new(12, 50), -1000)];
+        let expected: RangeSet = [100..105, 0..10, -985..-980, -960..-955].into_iter().collect();
+        assert_eq!(set.split_map(&table), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn union_matches_naive(a in range_set(), b in range_set()) {
+            let expected: HashSet<i64> = naive(&a).union(&naive(&b)).copied().collect();
+            prop_assert_eq!(naive(&a.union(&b)), expected);
+        }
+
+        #[test]
+        fn intersection_matches_naive(a in range_set(), b in range_set()) {
+            let expected: HashSet<i64> = naive(&a).intersection(&naive(&b)).copied().collect();
+            prop_assert_eq!(naive(&a.intersection(&b)), expected);
+        }
+
+        #[test]
+        fn difference_matches_naive(a in range_set(), b in range_set()) {
+            let expected: HashSet<i64> = naive(&a).difference(&naive(&b)).copied().collect();
+            prop_assert_eq!(naive(&a.difference(&b)), expected);
+        }
+
+        #[test]
+        fn count_and_contains_match_naive(a in range_set(), value in -60i64..80) {
+            let expected = naive(&a);
+            prop_assert_eq!(a.count(), expected.len() as u64);
+            prop_assert_eq!(a.contains(value), expected.contains(&value));
+        }
+
+        #[test]
+        fn intervals_stay_normalized(a in range_set(), b in range_set()) {
+            for set in [a.union(&b), a.intersection(&b), a.difference(&b)] {
+                prop_assert!(set.intervals().iter().all(|interval| !interval.is_empty()));
+                prop_assert!(set.intervals().windows(2).all(|pair| pair[0].end < pair[1].start));
+            }
+        }
+
+        #[test]
+        fn split_map_matches_naive(a in range_set(), sources in range_set(), offset in -30i64..30) {
+            let table: Vec<(Interval, i64)> = sources.intervals().iter().map(|source| (*source, offset)).collect();
+            let expected: HashSet<i64> = naive(&a).into_iter()
+                .map(|value| if sources.contains(value) { value + offset } else { value })
+                .collect();
+            prop_assert_eq!(naive(&a.split_map(&table)), expected);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Advent of Code 2023 solutions, usable as a library.
+//!
+//! Every day lives in [`days`] as a type implementing [`Solution`], alongside its parsers and
+//! algorithms, so they can be used on their own:
+//!
+//! ```
+//! use aoc2023::days::day15::{self, Day15};
+//! use aoc2023::Solution;
+//!
+//! assert_eq!(day15::hash("HASH"), 52);
+//!
+//! let day = Day15::parse("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7")?;
+//! assert_eq!(day.part1(), 1320);
+//! assert_eq!(day.part2(), 145);
+//! # Ok::<(), aoc2023::ParseError>(())
+//! ```
+//!
+//! [`days::get`] looks a day up by number for callers that only know it at runtime. The other
+//! modules are the building blocks the days share: grids, points, parsing, search and so on.
+
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod logging;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod report;
+pub mod search;
+pub mod solution;
+
+pub use error::ParseError;
+pub use solution::{Part, Solution};
@@ -0,0 +1,51 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Writes log records to stderr so they never mix with answers on stdout.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{:<5} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Maps `-v` flags onto a level: warnings and errors by default, then info, debug and trace.
+pub fn level_for(verbose: u8, quiet: bool) -> LevelFilter {
+    match (quiet, verbose) {
+        (true, _) => LevelFilter::Error,
+        (false, 0) => LevelFilter::Warn,
+        (false, 1) => LevelFilter::Info,
+        (false, 2) => LevelFilter::Debug,
+        (false, _) => LevelFilter::Trace,
+    }
+}
+
+/// Installs the stderr logger. Only the first call has any effect.
+pub fn init(level: LevelFilter) {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbosity_levels() {
+        assert_eq!(level_for(0, false), LevelFilter::Warn);
+        assert_eq!(level_for(2, false), LevelFilter::Debug);
+        assert_eq!(level_for(7, false), LevelFilter::Trace);
+        assert_eq!(level_for(3, true), LevelFilter::Error);
+    }
+}
@@ -1,3 +1,386 @@
-fn main() {
-    println!("Hello, world!");
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use aoc2023::answers::{Answers, Outcome};
+use aoc2023::bench;
+use aoc2023::days;
+use aoc2023::days::day1::{Day1, Language, Mode, Scanner, Vocabulary};
+use aoc2023::days::day2::{self, Bag, Day2};
+use aoc2023::input::InputSource;
+use aoc2023::logging;
+use aoc2023::report;
+use aoc2023::solution::{Part, Solution};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Directory containing day1.txt, day2.txt, ...
+    #[arg(long, global = true, env = "AOC_INPUTS_DIR", default_value = "inputs")]
+    inputs_dir: PathBuf,
+    /// Log more detail to stderr: -v for info, -vv for debug, -vvv for trace
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Only log errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, or just one part of it
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Solve every day in parallel and print a summary table
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// Only solve this part
+        #[arg(long)]
+        part: Option<Part>,
+        /// Read the puzzle input from this file, or from stdin if `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Print plain answers, or JSON records with the day, part, answer and duration in seconds
+        #[arg(long, value_enum, default_value_t = Output::Text)]
+        format: Output,
+    },
+    /// Sum day 1's calibration values, reading digits only, digits and words, or both
+    Calibrate {
+        /// Only use this mode: `digits` or `words`
+        #[arg(long)]
+        mode: Option<Mode>,
+        /// Also read the words for one to nine in this language, e.g. `german`
+        #[arg(long)]
+        language: Vec<Language>,
+        /// Also read TOKEN as the digit VALUE, e.g. `zero=0`
+        #[arg(long, value_name = "TOKEN=VALUE", value_parser = parse_token)]
+        token: Vec<(String, u32)>,
+        /// Print every line's matched tokens, their offsets and its calibration value
+        #[arg(long)]
+        explain: bool,
+        /// Read the calibration document from this file, or from stdin if `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Ask which of day 2's games a bag allows, and which bags allow them
+    Bag {
+        /// Only list the games this bag allows, e.g. `12 red, 13 green, 14 blue`
+        #[arg(long)]
+        bag: Option<Bag>,
+        /// Find the bag with the fewest cubes that allows at least this many games
+        #[arg(long, value_name = "K")]
+        at_least: Option<usize>,
+        /// Read the games from this file, or from stdin if `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Check every day against its accepted answers
+    Verify {
+        /// Answers file, defaults to answers.txt in the inputs directory
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Time the parse and both parts of every day
+    Bench {
+        /// Only benchmark this day
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Number of times to run each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Output {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logging::init(logging::level_for(cli.verbose, cli.quiet));
+    match cli.command {
+        Command::Run { all: true, part, format, .. } => run_all(part, &cli.inputs_dir, format),
+        Command::Run { day, part, input, format, .. } => {
+            let day = day.expect("clap requires --day without --all");
+            run(day, part, InputSource::new(input, cli.inputs_dir), format)
+        }
+        Command::Calibrate { mode, language, token, explain, input } => {
+            let extra = language.into_iter().fold(Vocabulary::default(), Vocabulary::with_language);
+            match token.into_iter().try_fold(extra, |extra, (token, value)| extra.with(token, value)) {
+                Ok(extra) => calibrate(mode, &extra, explain, InputSource::new(input, cli.inputs_dir)),
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Bag { bag, at_least, input } => query_bags(bag, at_least, InputSource::new(input, cli.inputs_dir)),
+        Command::Verify { answers } => {
+            let answers = answers.unwrap_or_else(|| cli.inputs_dir.join("answers.txt"));
+            verify(&answers, &cli.inputs_dir)
+        }
+        Command::Bench { day, runs, format } => run_bench(day, runs as usize, format, &cli.inputs_dir),
+    }
+}
+
+fn run(day: u8, part: Option<Part>, source: InputSource, format: Output) -> ExitCode {
+    let Some(solution) = days::get(day) else {
+        eprintln!("Day {day} is not solved");
+        return ExitCode::FAILURE;
+    };
+    let input = match source.load(day) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let answers = match parts.iter().map(|part| report::answer(solution, &input, *part)).collect::<Result<Vec<_>, _>>() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match format {
+        Output::Text if part.is_some() => println!("{}", answers[0].answer),
+        Output::Text => {
+            for answer in &answers {
+                println!("part {}: {}", answer.part, answer.answer);
+            }
+        }
+        Output::Json => println!("{}", report::to_json(&answers)),
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_all(part: Option<Part>, inputs_dir: &Path, format: Output) -> ExitCode {
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let source = InputSource::Dir(inputs_dir.to_path_buf());
+    let runs = report::run_all(days::ALL, &parts, |day| source.load(day));
+
+    match format {
+        Output::Text => print!("{}", report::to_table(&runs)),
+        Output::Json => {
+            let mut answers = vec![];
+            for run in &runs {
+                match &run.outcome {
+                    report::Outcome::Solved(solved) => answers.extend(solved.iter().cloned()),
+                    report::Outcome::Skipped(reason) => eprintln!("skipping day {}: {reason}", run.day),
+                    report::Outcome::Failed(reason) => eprintln!("day {} failed: {reason}", run.day),
+                }
+            }
+            println!("{}", report::to_json(&answers));
+        }
+    }
+    if runs.iter().any(|run| matches!(run.outcome, report::Outcome::Failed(_))) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn parse_token(s: &str) -> Result<(String, u32), String> {
+    let (token, value) = s.split_once('=').ok_or("expected TOKEN=VALUE")?;
+    let value = value.parse().map_err(|_| format!("expected a digit from 0 to 9, got {value:?}"))?;
+    Ok((token.to_string(), value))
+}
+
+fn calibrate(mode: Option<Mode>, extra: &Vocabulary, explain: bool, source: InputSource) -> ExitCode {
+    // A single mode streams the input, so it copes with files larger than memory. Both modes
+    // need it twice, which stdin can't do.
+    let loaded = match mode {
+        Some(_) => None,
+        None => match source.load(Day1::DAY) {
+            Ok(input) => Some(input),
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    for each in mode.map_or(Mode::ALL.to_vec(), |mode| vec![mode]) {
+        let reader: Box<dyn BufRead> = match &loaded {
+            Some(input) => Box::new(input.as_bytes()),
+            None => match source.open(Day1::DAY) {
+                Ok(reader) => reader,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            },
+        };
+        let scanner = Scanner::new(&Vocabulary::from(each).with_vocabulary(extra));
+        let sum = if explain {
+            scanner.explain(reader, |line_number, line, diagnosis| println!("{line_number:>5}  {line}  {diagnosis}"))
+        } else {
+            scanner.calibrate(reader)
+        };
+        match sum {
+            Ok(sum) if mode.is_some() => println!("{sum}"),
+            Ok(sum) => println!("{each}: {sum}"),
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn query_bags(bag: Option<Bag>, at_least: Option<usize>, source: InputSource) -> ExitCode {
+    let games = match source.load(Day2::DAY).map_err(|err| err.to_string()).and_then(|input| {
+        day2::parse_games(&input).map_err(|err| err.with_day(Day2::DAY).to_string())
+    }) {
+        Ok(games) => games,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let shown = match &bag {
+        Some(bag) => day2::feasible_games(&games, bag),
+        None if at_least.is_some() => vec![],
+        None => games.iter().collect(),
+    };
+    for game in &shown {
+        let minimal = game.minimal_bag();
+        println!("game {:>3}: needs {minimal} (power {})", game.id, minimal.power());
+    }
+    if let Some(bag) = &bag {
+        let ids = shown.iter().map(|game| game.id).sum::<u32>();
+        println!("{bag} allows {} of {} games, ids summing to {ids}", shown.len(), games.len());
+    }
+
+    if let Some(k) = at_least {
+        match day2::smallest_bag(&games, k) {
+            Some(smallest) => println!("smallest bag allowing {k} games: {smallest} ({} cubes)", smallest.total()),
+            None => {
+                eprintln!("there are only {} games", games.len());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn verify(answers_path: &Path, inputs_dir: &Path) -> ExitCode {
+    let answers = match Answers::load(answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days::ALL {
+        // The day's own input, plus any other inputs that have recorded answers.
+        let mut inputs = vec![];
+        let default_input = PathBuf::from(format!("day{}.txt", day.number));
+        if inputs_dir.join(&default_input).exists() {
+            inputs.push(default_input.clone());
+        }
+        for expected in answers.entries().iter().filter(|expected| expected.day == day.number) {
+            if !inputs.contains(&expected.input) {
+                inputs.push(expected.input.clone());
+            }
+        }
+        if inputs.is_empty() {
+            // Nothing to check, but the day still counts against the total.
+            for part in Part::ALL {
+                println!("day {:>2} part {part} {}: no input {}", day.number, default_input.display(), Outcome::Missing);
+                missing += 1;
+            }
+            continue;
+        }
+
+        for input in inputs {
+            let text = match InputSource::File(inputs_dir.join(&input)).load(day.number) {
+                Ok(text) => text,
+                Err(err) => {
+                    println!("day {:>2} {}: {err}", day.number, input.display());
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            for part in Part::ALL {
+                let actual = match (day.solve)(&text, part) {
+                    Ok(actual) => actual,
+                    Err(err) => {
+                        println!("day {:>2} part {part} {}: {err}", day.number, input.display());
+                        failed += 1;
+                        continue;
+                    }
+                };
+                let outcome = answers.check(day.number, part, &input, &actual);
+                match outcome {
+                    Outcome::Pass => passed += 1,
+                    Outcome::Fail { .. } => failed += 1,
+                    Outcome::Missing => missing += 1,
+                }
+                println!("day {:>2} part {part} {}: {actual} {outcome}", day.number, input.display());
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_bench(day: Option<u8>, runs: usize, format: Format, inputs_dir: &Path) -> ExitCode {
+    let source = InputSource::Dir(inputs_dir.to_path_buf());
+    let mut records = vec![];
+    for solution in days::ALL.iter().filter(|solution| day.is_none_or(|day| day == solution.number)) {
+        let input = match source.load(solution.number) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("skipping day {}: {err}", solution.number);
+                continue;
+            }
+        };
+        match bench::bench(solution, &input, runs) {
+            Ok(day_records) => records.extend(day_records),
+            Err(err) => eprintln!("skipping {err}"),
+        }
+    }
+    if records.is_empty() {
+        eprintln!("nothing to benchmark");
+        return ExitCode::FAILURE;
+    }
+
+    let output = match format {
+        Format::Table => bench::to_table(&records),
+        Format::Json => bench::to_json(&records),
+        Format::Csv => bench::to_csv(&records),
+    };
+    print!("{output}");
+    if !output.ends_with('\n') {
+        println!();
+    }
+    ExitCode::SUCCESS
 }
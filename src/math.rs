@@ -0,0 +1,152 @@
+use std::ops::{Div, Rem};
+
+/// Unsigned integer types the helpers in this module work over.
+pub trait Unsigned: Copy + Eq + Ord + Rem<Output = Self> + Div<Output = Self> {
+    const ZERO: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {
+        $(impl Unsigned for $ty {
+            const ZERO: Self = 0;
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$ty>::checked_mul(self, other)
+            }
+        })*
+    };
+}
+
+impl_unsigned!(u32, u64, u128, usize);
+
+/// Greatest common divisor, with `gcd(0, 0) == 0`.
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it doesn't fit in `T`. The lcm of anything and 0 is 0.
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all `values`, or `None` if there are none or it overflows.
+pub fn lcm_all<T: Unsigned>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    let mut values = values.into_iter();
+    let first = values.next()?;
+    values.try_fold(first, lcm)
+}
+
+/// Extended Euclid: returns `(g, x, y)` with `g = gcd(a, b) >= 0` and `a * x + b * y == g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `m`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a as i128, m as i128);
+    (g == 1).then(|| x.rem_euclid(m as i128) as u64)
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, returning
+/// `(x, m)` such that the solutions are exactly `x + k * m`. The moduli don't need to be coprime.
+/// Returns `None` if the system is inconsistent, any modulus is 0, or `m` doesn't fit in a `u64`.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    congruences.iter().try_fold((0, 1), |(r1, m1), &(r2, m2)| {
+        if m2 == 0 {
+            return None;
+        }
+        let r2 = r2 % m2;
+        let g = gcd(m1, m2);
+        let diff = r2 as i128 - r1 as i128;
+        if diff % g as i128 != 0 {
+            return None;
+        }
+
+        // Solve m1 * k ≡ diff (mod m2) for k, working modulo m2 / g.
+        let step = m2 / g;
+        let m = (m1 / g).checked_mul(m2)?;
+        let diff = (diff / g as i128).rem_euclid(step as i128) as u128;
+        let inverse = mod_inverse((m1 / g) % step, step)? as u128;
+        let k = diff * inverse % step as u128;
+        let x = (r1 as u128 + m1 as u128 * k) % m as u128;
+        Some((x as u64, m))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(0u64, 7), 7);
+        assert_eq!(gcd(0u64, 0), 0);
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(lcm(0u64, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(u64::MAX as u128, u64::MAX as u128 - 1), Some(u64::MAX as u128 * (u64::MAX as u128 - 1)));
+        assert_eq!(lcm_all([2u64, 3, 4]), Some(12));
+        assert_eq!(lcm_all(Vec::<u64>::new()), None);
+    }
+
+    #[test]
+    fn inverses() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 0)]), None);
+        let big = (1u64 << 61) - 1;
+        assert_eq!(crt(&[(5, big), (5, 3)]), Some((5, big * 3)));
+    }
+
+    #[test]
+    fn crt_matches_brute_force() {
+        for m1 in 1..12u64 {
+            for m2 in 1..12u64 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let m = lcm(m1, m2).unwrap();
+                        let expected = (0..m).find(|x| x % m1 == r1 && x % m2 == r2);
+                        assert_eq!(crt(&[(r1, m1), (r2, m2)]), expected.map(|x| (x, m)));
+                    }
+                }
+            }
+        }
+    }
+}
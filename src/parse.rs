@@ -0,0 +1,85 @@
+use std::str::FromStr;
+use nom::branch::alt;
+use nom::character::complete::{char, digit1, line_ending, multispace0, space1};
+use nom::combinator::{all_consuming, eof, map_res, not, opt, recognize};
+use nom::error::{context, VerboseError};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, terminated};
+
+use crate::error::ParseError;
+
+/// The result of every nom parser in this crate.
+pub type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// An unsigned decimal number, e.g. `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<'_, T> {
+    context("expected a number", map_res(digit1, str::parse))(input)
+}
+
+/// A decimal number with an optional sign, e.g. `-7` or `+3`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<'_, T> {
+    let sign = opt(alt((char('-'), char('+'))));
+    context("expected a number", map_res(recognize(pair(sign, digit1)), str::parse))(input)
+}
+
+/// One or more items separated by spaces or tabs, e.g. `1  2 3`.
+pub fn spaced<'a, T>(item: impl FnMut(&'a str) -> IResult<'a, T>) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(space1, item)
+}
+
+/// One or more records separated by `\n` or `\r\n`. Trailing line endings are left unconsumed
+/// rather than starting another record, so `cut` can be used on the record parser.
+pub fn records<'a, T>(record: impl FnMut(&'a str) -> IResult<'a, T>) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(terminated(line_ending, not(pair(multispace0, eof))), record)
+}
+
+/// Runs `parser` over the whole of `input`, allowing only trailing whitespace to remain.
+pub fn parse_all<'a, T>(input: &'a str, parser: impl FnMut(&'a str) -> IResult<'a, T>) -> Result<T, ParseError> {
+    all_consuming(terminated(parser, multispace0))(input)
+        .map(|(_, value)| value)
+        .map_err(|err| ParseError::from_nom(input, err))
+}
+
+/// Runs `parser` over each line of `input` separately, reporting errors against the right line.
+pub fn parse_lines<'a, T>(input: &'a str, mut parser: impl FnMut(&'a str) -> IResult<'a, T>) -> Result<Vec<T>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            all_consuming(&mut parser)(line)
+                .map(|(_, value)| value)
+                .map_err(|err| ParseError::from_nom(line, err).on_line(idx + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::combinator::cut;
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(unsigned::<u32>("12 rest"), Ok((" rest", 12)));
+        assert_eq!(signed::<i64>("-12"), Ok(("", -12)));
+        assert_eq!(signed::<i64>("+3"), Ok(("", 3)));
+        assert!(unsigned::<u32>("-1").is_err());
+        assert_eq!(parse_all("1  2\t3\n", spaced(unsigned::<u8>)), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn line_endings() {
+        let input = "1 2\r\n3\n4 5\r\n";
+        assert_eq!(parse_all(input, records(spaced(unsigned::<u8>))), Ok(vec![vec![1, 2], vec![3], vec![4, 5]]));
+        assert_eq!(parse_all(input, records(cut(spaced(unsigned::<u8>)))), Ok(vec![vec![1, 2], vec![3], vec![4, 5]]));
+        assert_eq!(parse_lines(input, spaced(signed::<i8>)), Ok(vec![vec![1, 2], vec![3], vec![4, 5]]));
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse_all("1 2\n3 x", records(spaced(unsigned::<u8>))).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 3: unexpected input at \"x\"");
+        // Overflowing numbers backtrack out of the list like any other non-number.
+        let err = parse_lines("1 2\n3 300", spaced(unsigned::<u8>)).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: unexpected input at \" 300\"");
+    }
+}
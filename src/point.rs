@@ -0,0 +1,192 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point or offset on an integer plane, with `y` growing downwards as in the puzzle maps.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self {
+            x,
+            y,
+        }
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn step(self, dir: Dir) -> Point {
+        self + dir.delta()
+    }
+
+    /// The four points sharing an edge with this one, clockwise from north.
+    pub fn neighbours(self) -> [Point; 4] {
+        Dir::ALL.map(|dir| self.step(dir))
+    }
+
+    /// The eight points sharing an edge or a corner with this one, clockwise from north.
+    pub fn neighbours8(self) -> [Point; 8] {
+        [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)]
+            .map(|(x, y)| self + Point::new(x, y))
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Dir> for Point {
+    fn from(dir: Dir) -> Self {
+        dir.delta()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the four cardinal directions, with north pointing up the map (towards smaller `y`).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir::North => Point::new(0, -1),
+            Dir::East => Point::new(1, 0),
+            Dir::South => Point::new(0, 1),
+            Dir::West => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Dir {
+        match self {
+            Dir::North => Dir::West,
+            Dir::East => Dir::North,
+            Dir::South => Dir::East,
+            Dir::West => Dir::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        match self {
+            Dir::North => Dir::East,
+            Dir::East => Dir::South,
+            Dir::South => Dir::West,
+            Dir::West => Dir::North,
+        }
+    }
+
+    pub fn reverse(self) -> Dir {
+        match self {
+            Dir::North => Dir::South,
+            Dir::East => Dir::West,
+            Dir::South => Dir::North,
+            Dir::West => Dir::East,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir::North | Dir::South)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(-3, 5);
+        assert_eq!(a + b, Point::new(-2, 7));
+        assert_eq!(a - b, Point::new(4, -3));
+        assert_eq!(b * 2, Point::new(-6, 10));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(Point::from((4, 4)).to_string(), "(4, 4)");
+    }
+
+    #[test]
+    fn neighbours() {
+        let p = Point::new(3, 3);
+        assert_eq!(p.neighbours(), [(3, 2), (4, 3), (3, 4), (2, 3)].map(Point::from));
+        assert_eq!(p.neighbours8().len(), 8);
+        assert!(p.neighbours().iter().all(|n| p.neighbours8().contains(n)));
+        assert!(p.neighbours8().iter().all(|n| n.x.abs_diff(p.x) <= 1 && n.y.abs_diff(p.y) <= 1 && *n != p));
+    }
+
+    #[test]
+    fn turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.reverse().delta(), -dir.delta());
+            assert_eq!(Point::ORIGIN.step(dir).step(dir.reverse()), Point::ORIGIN);
+        }
+        assert_eq!(Dir::North.turn_right(), Dir::East);
+    }
+}
@@ -0,0 +1,179 @@
+use std::any::Any;
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+use rayon::prelude::*;
+use serde::{Serialize, Serializer};
+use crate::days::Day;
+use crate::error::ParseError;
+use crate::input::InputError;
+use crate::solution::Part;
+
+/// One answered part of a day, with how long parsing and solving it took.
+#[derive(Clone, Debug, Serialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Serialized as seconds.
+    #[serde(serialize_with = "seconds")]
+    pub duration: Duration,
+}
+
+fn seconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// Solves `part` of `day` on `input`, timing the parse and the part together.
+pub fn answer(day: &Day, input: &str, part: Part) -> Result<Answer, ParseError> {
+    let start = Instant::now();
+    let answer = (day.solve)(input, part)?;
+    Ok(Answer {
+        day: day.number,
+        part,
+        answer,
+        duration: start.elapsed(),
+    })
+}
+
+pub fn to_json(answers: &[Answer]) -> String {
+    serde_json::to_string_pretty(answers).expect("answers always serialize")
+}
+
+/// How one day went when running every day.
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Solved(Vec<Answer>),
+    /// There was no input for the day.
+    Skipped(String),
+    /// The input couldn't be read or parsed, or the solution panicked.
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub outcome: Outcome,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = payload.downcast_ref::<&str>().map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string());
+    format!("panicked: {message}")
+}
+
+fn run_day(day: &Day, parts: &[Part], load: &(impl Fn(u8) -> Result<String, InputError> + Sync)) -> Outcome {
+    let input = match load(day.number) {
+        Ok(input) => input,
+        Err(err) if err.is_missing() => return Outcome::Skipped(err.to_string()),
+        Err(err) => return Outcome::Failed(err.to_string()),
+    };
+
+    let answers = parts.par_iter()
+        .map(|part| {
+            panic::catch_unwind(AssertUnwindSafe(|| answer(day, &input, *part)))
+                .map_err(panic_message)?
+                .map_err(|err| err.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>();
+    match answers {
+        Ok(answers) => Outcome::Solved(answers),
+        Err(message) => Outcome::Failed(message),
+    }
+}
+
+/// Runs `parts` of every day in `days` concurrently on the rayon pool. A day that fails or panics
+/// is reported in its `DayRun` without affecting the others. The results are in the order of `days`.
+pub fn run_all(days: &[Day], parts: &[Part], load: impl Fn(u8) -> Result<String, InputError> + Sync) -> Vec<DayRun> {
+    days.par_iter()
+        .map(|day| DayRun {
+            day: day.number,
+            outcome: run_day(day, parts, &load),
+        })
+        .collect()
+}
+
+/// A summary with one row per day: its answers and the total time, or why it has none.
+pub fn to_table(runs: &[DayRun]) -> String {
+    let mut table = format!("{:>3}  {:<16}  {:<16}  {:>12}\n", "day", "part 1", "part 2", "time");
+    for run in runs {
+        let _ = match &run.outcome {
+            Outcome::Solved(answers) => {
+                let part = |part| answers.iter().find(|answer| answer.part == part).map_or("", |answer| answer.answer.as_str());
+                let time = answers.iter().map(|answer| answer.duration).sum::<Duration>();
+                writeln!(table, "{:>3}  {:<16}  {:<16}  {:>12}", run.day, part(Part::One), part(Part::Two), format!("{time:.2?}"))
+            }
+            Outcome::Skipped(reason) => writeln!(table, "{:>3}  skipped: {reason}", run.day),
+            Outcome::Failed(reason) => writeln!(table, "{:>3}  FAILED: {reason}", run.day),
+        };
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use crate::days::{self, Day};
+    use crate::input::InputSource;
+    use crate::solution::{self, Solution};
+    use super::*;
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 25;
+        type Answer = u8;
+
+        fn parse(_: &str) -> Result<Self, ParseError> {
+            Ok(Panics)
+        }
+
+        fn part1(&self) -> u8 {
+            1
+        }
+
+        fn part2(&self) -> u8 {
+            panic!("part 2 is not done")
+        }
+    }
+
+    #[test]
+    fn run_all_reports_each_day() {
+        let panics = Day {
+            number: 25,
+            solve: solution::solve::<Panics>,
+            time: solution::time::<Panics>,
+        };
+        let days = [panics, *days::get(15).unwrap(), *days::get(9).unwrap(), *days::get(3).unwrap()];
+        let missing = InputSource::new(None, PathBuf::from("does-not-exist"));
+        let load = |day| match day {
+            25 => Ok(String::new()),
+            15 => Ok("rn=1".to_string()),
+            9 => Ok("1 x".to_string()),
+            _ => missing.load(day),
+        };
+
+        let runs = run_all(&days, &Part::ALL, load);
+        assert_eq!(runs.iter().map(|run| run.day).collect::<Vec<_>>(), vec![25, 15, 9, 3]);
+        assert!(matches!(&runs[0].outcome, Outcome::Failed(message) if message == "panicked: part 2 is not done"));
+        assert!(matches!(&runs[1].outcome, Outcome::Solved(answers) if answers[0].answer == "30" && answers[1].answer == "1"));
+        assert!(matches!(&runs[2].outcome, Outcome::Failed(message) if message.starts_with("day 9: line 1, column 2")));
+        assert!(matches!(&runs[3].outcome, Outcome::Skipped(_)));
+
+        let table = to_table(&runs);
+        assert!(table.lines().nth(1).unwrap().starts_with(" 25  FAILED: panicked"));
+        assert!(table.lines().nth(2).unwrap().starts_with(" 15  30                1   "));
+    }
+
+    #[test]
+    fn json_records() {
+        let day = days::get(15).unwrap();
+        let mut answer = answer(day, "rn=1", Part::One).unwrap();
+        assert_eq!(answer.answer, "30");
+        answer.duration = Duration::from_millis(1500);
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&[answer])).unwrap();
+        assert_eq!(json, serde_json::json!([{ "day": 15, "part": 1, "answer": "30", "duration": 1.5 }]));
+    }
+}
@@ -0,0 +1,260 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Edge costs for the weighted searches. `Default` must be zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Everything a search reached: the cost to each state and how it was first reached.
+#[derive(Clone, Debug)]
+pub struct Paths<S, C> {
+    reached: HashMap<S, (C, Option<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Paths<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Self {
+            reached: HashMap::from([(start, (zero, None))]),
+        }
+    }
+
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.reached.get(state).map(|(cost, _)| *cost)
+    }
+
+    pub fn contains(&self, state: &S) -> bool {
+        self.reached.contains_key(state)
+    }
+
+    /// The path from the start to `state`, including both ends.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let mut path = vec![state.clone()];
+        let mut parent = self.reached.get(state)?.1.clone();
+        while let Some(state) = parent {
+            parent = self.reached[&state].1.clone();
+            path.push(state);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn costs(&self) -> impl Iterator<Item = (&S, C)> {
+        self.reached.iter().map(|(state, (cost, _))| (state, *cost))
+    }
+
+    pub fn into_costs(self) -> HashMap<S, C> {
+        self.reached.into_iter().map(|(state, (cost, _))| (state, cost)).collect()
+    }
+}
+
+/// Breadth-first search from `start` until `is_goal` matches or every reachable state is seen.
+/// Each step costs 1.
+fn bfs_until<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> (Paths<S, usize>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, steps)) = queue.pop_front() {
+        if is_goal(&state) {
+            return (paths, Some(state));
+        }
+        for next in successors(&state) {
+            if !paths.contains(&next) {
+                paths.reached.insert(next.clone(), (steps + 1, Some(state.clone())));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    (paths, None)
+}
+
+/// Every state reachable from `start`, with the fewest steps needed to reach it.
+pub fn bfs<S, I>(start: S, successors: impl FnMut(&S) -> I) -> Paths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_until(start, successors, |_| false).0
+}
+
+/// The shortest path from `start` to the nearest state matching `is_goal`, and its length.
+pub fn bfs_to<S, I>(start: S, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (paths, goal) = bfs_until(start, successors, is_goal);
+    let goal = goal?;
+    Some((paths.path_to(&goal)?, paths.cost(&goal)?))
+}
+
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    /// Reversed, so the `BinaryHeap` pops the lowest priority first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// A* from `start`, stopping at the first state matching `is_goal`. With a heuristic of zero this
+/// is Dijkstra. The heuristic must never overestimate the remaining cost.
+fn astar_until<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Paths<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = Paths::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    while let Some(Entry { cost, state, .. }) = queue.pop() {
+        if paths.cost(&state).is_some_and(|best| best < cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            return (paths, Some(state));
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if paths.cost(&next).is_none_or(|best| next_cost < best) {
+                paths.reached.insert(next.clone(), (next_cost, Some(state.clone())));
+                queue.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    (paths, None)
+}
+
+/// Every state reachable from `start`, with the cheapest cost to reach it.
+pub fn dijkstra<S, C, I>(start: S, successors: impl FnMut(&S) -> I) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_until(start, successors, |_| C::default(), |_| false).0
+}
+
+/// The cheapest path from `start` to a state matching `is_goal`, and its cost.
+pub fn dijkstra_to<S, C, I>(start: S, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// The cheapest path from `start` to a state matching `is_goal`, and its cost, guided by a
+/// `heuristic` that must never overestimate the remaining cost.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (paths, goal) = astar_until(start, successors, heuristic, is_goal);
+    let goal = goal?;
+    Some((paths.path_to(&goal)?, paths.cost(&goal)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Grid, Pos};
+    use super::*;
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.
+...#...";
+
+    fn open(maze: &Grid<char>, pos: &Pos) -> Vec<Pos> {
+        maze.neighbours(*pos).filter(|next| maze[*next] == '.').collect()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let maze = Grid::parse_chars(MAZE).unwrap();
+        let paths = bfs((0, 0), |pos| open(&maze, pos));
+        assert_eq!(paths.cost(&(6, 4)), Some(10));
+        assert_eq!(paths.cost(&(2, 0)), None);
+        assert_eq!(paths.path_to(&(0, 2)), Some(vec![(0, 0), (0, 1), (0, 2)]));
+
+        let (path, steps) = bfs_to((0, 0), |pos| open(&maze, pos), |pos| *pos == (6, 0)).unwrap();
+        assert_eq!(steps, 12);
+        assert_eq!(path.len(), 13);
+        assert!(path.windows(2).all(|pair| maze.neighbours(pair[0]).any(|pos| pos == pair[1])));
+        assert_eq!(bfs_to((0, 0), |pos| open(&maze, pos), |pos| *pos == (2, 0)), None);
+    }
+
+    #[test]
+    fn weighted() {
+        // A direct edge that's more expensive than going the long way round.
+        let edges = |node: &char| -> Vec<(char, u32)> {
+            match node {
+                'a' => vec![('b', 1), ('d', 10)],
+                'b' => vec![('c', 2)],
+                'c' => vec![('d', 3)],
+                _ => vec![],
+            }
+        };
+        assert_eq!(dijkstra_to('a', edges, |node| *node == 'd'), Some((vec!['a', 'b', 'c', 'd'], 6)));
+        let paths = dijkstra('a', edges);
+        assert_eq!(paths.into_costs(), HashMap::from([('a', 0), ('b', 1), ('c', 3), ('d', 6)]));
+    }
+
+    #[test]
+    fn astar_matches_bfs() {
+        let maze = Grid::parse_chars(MAZE).unwrap();
+        let goal = (6, 0);
+        let successors = |pos: &Pos| open(&maze, pos).into_iter().map(|next| (next, 1));
+        let manhattan = |pos: &Pos| pos.0.abs_diff(goal.0) + pos.1.abs_diff(goal.1);
+        let (path, cost) = astar((0, 0), successors, manhattan, |pos| *pos == goal).unwrap();
+        assert_eq!(cost, 12);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use crate::error::ParseError;
+use crate::input::InputSource;
+
+/// A day's puzzle. The input is parsed once and both parts are answered from the parsed form.
+pub trait Solution: Sized {
+    const DAY: u8;
+    type Answer: IntoAnswer;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+    fn part1(&self) -> Self::Answer;
+    fn part2(&self) -> Self::Answer;
+}
+
+/// What a part returns: a plain answer, or a `Result` for days whose input can be valid for one
+/// part but not the other.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<String, ParseError>;
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Result<String, ParseError> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_into_answer!(u8, u32, u64, usize, i64, String);
+
+impl<T: IntoAnswer> IntoAnswer for Result<T, ParseError> {
+    fn into_answer(self) -> Result<String, ParseError> {
+        self?.into_answer()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Serialized as the number `1` or `2`.
+impl serde::Serialize for Part {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, got {s:?}")),
+        }
+    }
+}
+
+/// Parses `input` as `S` and answers the requested part.
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<String, ParseError> {
+    let solution = S::parse(input).map_err(|err| err.with_day(S::DAY))?;
+    match part {
+        Part::One => solution.part1().into_answer(),
+        Part::Two => solution.part2().into_answer(),
+    }
+    .map_err(|err| err.with_day(S::DAY))
+}
+
+/// How long each stage of a solution took.
+#[derive(Clone, Copy, Debug)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Parses `input` as `S` and answers both parts, timing each stage.
+pub fn time<S: Solution>(input: &str) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let solution = black_box(S::parse(input).map_err(|err| err.with_day(S::DAY))?);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(solution.part1());
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    black_box(solution.part2());
+    let part2 = start.elapsed();
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// The `main` of the standalone `examples/dayN.rs` binaries. Reads the input from the path given
+/// as the first argument (`-` for stdin), or `inputs/dayN.txt`, and prints both answers.
+pub fn run_standalone<S: Solution>() -> ExitCode {
+    let source = InputSource::new(std::env::args_os().nth(1).map(PathBuf::from), PathBuf::from("inputs"));
+    let solution = source.load(S::DAY)
+        .map_err(|err| err.to_string())
+        .and_then(|input| S::parse(&input).map_err(|err| err.with_day(S::DAY).to_string()));
+    match solution {
+        Ok(solution) => {
+            let mut status = ExitCode::SUCCESS;
+            for (part, answer) in [(Part::One, solution.part1().into_answer()), (Part::Two, solution.part2().into_answer())] {
+                match answer {
+                    Ok(answer) => println!("part {part}: {answer}"),
+                    Err(err) => {
+                        eprintln!("part {part}: {}", err.with_day(S::DAY));
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+            status
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}